@@ -0,0 +1,128 @@
+//! Compares the priority queues in `util::heap` on a reindeer maze search, where each state is a
+//! cell and facing, moving forward costs 1 and turning costs 1000. Each search explores the whole
+//! maze rather than stopping at the end, so every queue does the same work.
+//!
+//! Run with `cargo bench --bench heap`.
+#![feature(test)]
+extern crate test;
+
+use aoc::util::grid::*;
+use aoc::util::heap::*;
+use aoc::util::point::*;
+use test::Bencher;
+
+const SIZE: i32 = 141;
+const DIRECTIONS: [Point; 4] = [RIGHT, DOWN, LEFT, UP];
+
+/// Open maze with pseudo random walls and a clear border path, so the end is always reachable.
+fn maze() -> Grid<u8> {
+    let mut grid = Grid::new(SIZE, SIZE, b'.');
+    let mut seed = 1_u64;
+
+    for point in grid.points().collect::<Vec<_>>() {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let border = point.x == 0 || point.y == 0 || point.x == SIZE - 1 || point.y == SIZE - 1;
+        if !border && (seed >> 33).is_multiple_of(4) {
+            grid[point] = b'#';
+        }
+    }
+
+    grid
+}
+
+/// States reachable from `state` with their cost.
+fn moves(grid: &Grid<u8>, state: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let (index, direction) = (state / 4, state % 4);
+    let point = grid.point_of(index);
+    let forward = point + DIRECTIONS[direction];
+    let ahead = grid.contains(forward) && grid[forward] == b'.';
+
+    let step = ahead.then(|| (grid.index_of(forward) * 4 + direction, 1));
+    let left = (index * 4 + (direction + 3) % 4, 1000);
+    let right = (index * 4 + (direction + 1) % 4, 1000);
+
+    step.into_iter().chain([left, right])
+}
+
+fn end(grid: &Grid<u8>) -> usize {
+    grid.index_of(Point::new(SIZE - 1, SIZE - 1))
+}
+
+fn min_heap(grid: &Grid<u8>) -> usize {
+    let mut cost = vec![usize::MAX; grid.bytes.len() * 4];
+    let mut todo = MinHeap::new();
+    cost[0] = 0;
+    todo.push(0, 0);
+
+    while let Some((current, state)) = todo.pop() {
+        if current > cost[state] {
+            continue;
+        }
+        for (next, weight) in moves(grid, state) {
+            if current + weight < cost[next] {
+                cost[next] = current + weight;
+                todo.push(current + weight, next);
+            }
+        }
+    }
+
+    (0..4).map(|direction| cost[end(grid) * 4 + direction]).min().unwrap()
+}
+
+fn indexed_heap(grid: &Grid<u8>) -> usize {
+    let mut cost = vec![usize::MAX; grid.bytes.len() * 4];
+    let mut todo = IndexedHeap::new(grid.bytes.len() * 4);
+    todo.push(0, 0);
+
+    while let Some((current, state)) = todo.pop() {
+        cost[state] = current;
+        for (next, weight) in moves(grid, state) {
+            if cost[next] == usize::MAX {
+                todo.push_or_decrease(next, current + weight);
+            }
+        }
+    }
+
+    (0..4).map(|direction| cost[end(grid) * 4 + direction]).min().unwrap()
+}
+
+fn bucket_queue(grid: &Grid<u8>) -> usize {
+    let mut cost = vec![usize::MAX; grid.bytes.len() * 4];
+    let mut todo = BucketQueue::new(1000);
+    cost[0] = 0;
+    todo.push(0, 0);
+
+    while let Some((current, state)) = todo.pop() {
+        if current > cost[state] {
+            continue;
+        }
+        for (next, weight) in moves(grid, state) {
+            if current + weight < cost[next] {
+                cost[next] = current + weight;
+                todo.push(current + weight, next);
+            }
+        }
+    }
+
+    (0..4).map(|direction| cost[end(grid) * 4 + direction]).min().unwrap()
+}
+
+#[bench]
+fn bench_min_heap(b: &mut Bencher) {
+    let grid = maze();
+    b.iter(|| min_heap(&grid));
+}
+
+#[bench]
+fn bench_indexed_heap(b: &mut Bencher) {
+    let grid = maze();
+    assert_eq!(indexed_heap(&grid), min_heap(&grid));
+    b.iter(|| indexed_heap(&grid));
+}
+
+#[bench]
+fn bench_bucket_queue(b: &mut Bencher) {
+    let grid = maze();
+    assert_eq!(bucket_queue(&grid), min_heap(&grid));
+    b.iter(|| bucket_queue(&grid));
+}
@@ -1,5 +1,8 @@
+use crate::util::compress::*;
+use crate::util::grid::*;
 use crate::util::iter::*;
 use crate::util::parse::*;
+use crate::util::point::*;
 
 enum Command {
     On,
@@ -53,73 +56,44 @@ pub fn parse(input: &str) -> (u32, u32) {
     let second = input.iter_unsigned().chunk::<4>();
     let instructions: Vec<_> = first.zip(second).map(Instruction::from).collect();
 
-    let mut xs = vec![0, 1000];
-    let mut ys = vec![0, 1000];
+    let rectangles = instructions.iter().map(|instruction| &instruction.rectangle);
+    let compression = Compression::new(
+        rectangles.clone().flat_map(|r| [r.x1 as i64, r.x2 as i64 + 1]).chain([0, 1000]),
+        rectangles.flat_map(|r| [r.y1 as i64, r.y2 as i64 + 1]).chain([0, 1000]),
+    );
 
-    for instruction in &instructions {
-        let Rectangle { x1, x2, y1, y2 } = instruction.rectangle;
-        xs.push(x1);
-        xs.push(x2 + 1);
-        ys.push(y1);
-        ys.push(y2 + 1);
-    }
-
-    xs.sort_unstable();
-    xs.dedup();
-    ys.sort_unstable();
-    ys.dedup();
-
-    let mut x_index_from = [0; 1001];
-    for (i, &x) in xs.iter().enumerate() {
-        x_index_from[x as usize] = i;
-    }
-
-    let mut y_index_from = [0; 1001];
-    for (i, &y) in ys.iter().enumerate() {
-        y_index_from[y as usize] = i;
-    }
-
-    let stride = xs.len();
-    let capacity = stride * ys.len();
-    let mut up = vec![false; capacity];
-    let mut left = vec![false; capacity];
-    let mut previous = vec![(false, 0); capacity];
+    // mark the edges of every rectangle, as cells within the same edges are always identical
+    let (width, height) = (compression.xs.len() as i32, compression.ys.len() as i32);
+    let mut up = Grid::new(width, height, false);
+    let mut left = Grid::new(width, height, false);
 
     for instruction in &instructions {
         let Rectangle { x1, x2, y1, y2 } = instruction.rectangle;
-        let x1 = x_index_from[x1 as usize];
-        let x2 = x_index_from[(x2 + 1) as usize];
-        let y1 = y_index_from[y1 as usize];
-        let y2 = y_index_from[(y2 + 1) as usize];
-
-        for x in x1..(x2 + 1) {
-            up[stride * y1 + x] = true;
-            up[stride * y2 + x] = true;
+        let from = compression.index(Point64::new(x1 as i64, y1 as i64)).unwrap();
+        let to = compression.index(Point64::new(x2 as i64 + 1, y2 as i64 + 1)).unwrap();
+
+        for x in from.x..(to.x + 1) {
+            up[Point::new(x, from.y)] = true;
+            up[Point::new(x, to.y)] = true;
         }
-        for y in y1..(y2 + 1) {
-            left[stride * y + x1] = true;
-            left[stride * y + x2] = true;
+        for y in from.y..(to.y + 1) {
+            left[Point::new(from.x, y)] = true;
+            left[Point::new(to.x, y)] = true;
         }
     }
 
-    let mut result1 = 0;
-    let mut result2 = 0;
-
-    for j in 0..ys.len() - 1 {
-        let y1 = ys[j];
-        let y2 = ys[j + 1];
+    let mut lights = compression.grid((false, 0_u8));
 
-        for i in 0..xs.len() - 1 {
-            let x1 = xs[i];
-            let x2 = xs[i + 1];
-            let area = (x2 - x1) * (y2 - y1);
-            let index = stride * j + i;
-
-            let current = if i > 0 && !left[index] {
-                previous[index - 1]
-            } else if j > 0 && !up[index] {
-                previous[index - stride]
+    for y in 0..lights.height {
+        for x in 0..lights.width {
+            let cell = Point::new(x, y);
+            lights[cell] = if cell.x > 0 && !left[cell] {
+                lights[cell + LEFT]
+            } else if cell.y > 0 && !up[cell] {
+                lights[cell + UP]
             } else {
+                let origin = compression.origin(cell);
+                let (x1, y1) = (origin.x as u32, origin.y as u32);
                 let mut light = false;
                 let mut brightness: u8 = 0;
 
@@ -144,16 +118,13 @@ pub fn parse(input: &str) -> (u32, u32) {
 
                 (light, brightness)
             };
-
-            previous[index] = current;
-            if current.0 {
-                result1 += area;
-            }
-            result2 += current.1 as u32 * area;
         }
     }
 
-    (result1, result2)
+    let result1 = compression.weighted_sum(&lights, |&(light, _)| light as i64);
+    let result2 = compression.weighted_sum(&lights, |&(_, brightness)| brightness as i64);
+
+    (result1 as u32, result2 as u32)
 }
 
 pub fn part1(input: &(u32, u32)) -> u32 {
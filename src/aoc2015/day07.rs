@@ -1,7 +1,6 @@
 use std::collections::HashMap;
 
-use regex::Captures;
-use regex::Regex;
+use crate::util::parse::*;
 
 #[derive(Debug, PartialEq)]
 pub enum Signal {
@@ -14,78 +13,31 @@ pub enum Signal {
 }
 
 pub fn parse(input: &str) -> HashMap<String, Signal> {
-    let mut signals = HashMap::new();
-
-    let re_assign = Regex::new(r"^(\w+) -> (\D+)$").unwrap();
-    let re_and = Regex::new(r"^(\w+) AND (\w+) -> (\D+)$").unwrap();
-    let re_or = Regex::new(r"^(\w+) OR (\w+) -> (\D+)$").unwrap();
-    let re_not = Regex::new(r"^NOT (\w+) -> (\D+)$").unwrap();
-    let re_lshift = Regex::new(r"^(\w+) LSHIFT (\d+) -> (\D+)$").unwrap();
-    let re_rshift = Regex::new(r"^(\w+) RSHIFT (\d+) -> (\D+)$").unwrap();
-
-    for line in input.lines() {
+    let assign = Pattern::new("{} -> {wire}");
+    let and = Pattern::new("{} AND {} -> {wire}");
+    let or = Pattern::new("{} OR {} -> {wire}");
+    let not = Pattern::new("NOT {} -> {wire}");
+    let lshift = Pattern::new("{} LSHIFT {bits} -> {wire}");
+    let rshift = Pattern::new("{} RSHIFT {bits} -> {wire}");
+
+    input.lines().map(|line| {
         let line = line.trim();
-        if parsed_by(&re_assign, line, &mut |cap| {
-            let name = cap.get(2).unwrap().as_str().to_string();
-            signals.insert(name, Signal::Assign(
-                cap.get(1).unwrap().as_str().to_string()
-            ));
-        }) { continue; }
-
-        if parsed_by(&re_and, line, &mut |cap| {
-            let name = cap.get(3).unwrap().as_str().to_string();
-            signals.insert(name, Signal::And(
-                cap.get(1).unwrap().as_str().to_string(),
-                cap.get(2).unwrap().as_str().to_string()
-            ));
-        }) { continue; }
-
-        if parsed_by(&re_or, line, &mut |cap| {
-            let name = cap.get(3).unwrap().as_str().to_string();
-            signals.insert(name, Signal::Or(
-                cap.get(1).unwrap().as_str().to_string(),
-                cap.get(2).unwrap().as_str().to_string()
-            ));
-        }) { continue; }
-
-        if parsed_by(&re_not, line, &mut |cap| {
-            let name = cap.get(2).unwrap().as_str().to_string();
-            signals.insert(name, Signal::Not(
-                cap.get(1).unwrap().as_str().to_string()
-            ));
-        }) { continue; }
-
-        if parsed_by(&re_lshift, line, &mut |cap| {
-            let name = cap.get(3).unwrap().as_str().to_string();
-            signals.insert(name, Signal::LShift(
-                cap.get(1).unwrap().as_str().to_string(),
-                cap.get(2).unwrap().as_str().parse().unwrap()
-            ));
-        }) { continue; }
-
-        if parsed_by(&re_rshift, line, &mut |cap| {
-            let name = cap.get(3).unwrap().as_str().to_string();
-            signals.insert(name, Signal::RShift(
-                cap.get(1).unwrap().as_str().to_string(),
-                cap.get(2).unwrap().as_str().parse().unwrap()
-            ));
-        }) { continue; }
-
-        unreachable!("found a line that couldn't be parsed: {}", line);
-    };
-    signals
-}
-
-fn parsed_by<'t, F>(re: &Regex, line: &'t str, f: &mut F) -> bool
-    where F: FnMut(Captures<'t>)
-{
-    return match re.captures(line) {
-        Some(cap) => {
-            f(cap);
-            true
-        }
-        None => false,
-    };
+        let (signal, wire) = if let Ok((a, b, wire)) = and.parse::<(String, String, String)>(line) {
+            (Signal::And(a, b), wire)
+        } else if let Ok((a, b, wire)) = or.parse(line) {
+            (Signal::Or(a, b), wire)
+        } else if let Ok((a, wire)) = not.parse(line) {
+            (Signal::Not(a), wire)
+        } else if let Ok((a, bits, wire)) = lshift.parse(line) {
+            (Signal::LShift(a, bits), wire)
+        } else if let Ok((a, bits, wire)) = rshift.parse(line) {
+            (Signal::RShift(a, bits), wire)
+        } else {
+            let (a, wire) = assign.parse(line).unwrap_or_else(|e| panic!("{e}"));
+            (Signal::Assign(a), wire)
+        };
+        (wire, signal)
+    }).collect()
 }
 
 fn get_val(signals: &HashMap<String, Signal>, cache: &mut HashMap<String, u16>, s: &String) -> u16 {
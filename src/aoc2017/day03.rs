@@ -1,4 +1,4 @@
-use crate::util::{parse::ParseOps, point::*, hash::*};
+use crate::util::{parse::ParseOps, point::*, sparse::SparseGrid};
 
 pub fn parse(input: &str) -> u32 {
     input.unsigned()
@@ -43,7 +43,7 @@ pub fn spiral() -> impl Iterator<Item = Point> {
 }
 
 pub fn spiral_sum_sequence() -> impl Iterator<Item = i64> {
-    let mut points = FastMap::new();
+    let mut points = SparseGrid::new(0);
     
     // Initialize with first value at origin
     points.insert(ORIGIN, 1);
@@ -55,7 +55,7 @@ pub fn spiral_sum_sequence() -> impl Iterator<Item = i64> {
             .map(move |point| {
                 let sum = point.adjacent_with_diagonals()
                     .iter()
-                    .map(|&adj_point| points.get(&adj_point).copied().unwrap_or(0))
+                    .map(|&adj_point| points[adj_point])
                     .sum();
                 
                 points.insert(point, sum);
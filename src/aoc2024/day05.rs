@@ -2,7 +2,7 @@ use crate::util::iter::*;
 use crate::util::parse::*;
 
 pub fn parse(input: &str) -> (usize, usize) {
-    let (rules_data, checks_data) = input.split_once("\n\n").unwrap();
+    let [rules_data, checks_data] = input.sections().unwrap();
     
     // Create and populate the rules array
     let mut rules = [[false; 100]; 100];
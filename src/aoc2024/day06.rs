@@ -1,14 +1,14 @@
-use crate::util::{grid::*, hash::*, point::*};
+use crate::util::{direction::Direction, grid::*, hash::*, point::*};
 use rayon::prelude::*;
 
 pub fn parse(input: &str) -> Grid<u8> {
     Grid::parse(input)
 }
 
-fn walk_grid(input: &Grid<u8>) -> FastSet<Point> {
+pub fn walk_grid(input: &Grid<u8>) -> FastSet<Point> {
     let mut positions = FastSet::with_capacity((input.width * input.height) as usize);
     let mut guard_location = input.find(b'^').unwrap();
-    let mut guard_direction = UP;
+    let mut guard_direction = Direction::North;
 
     positions.insert(guard_location);
 
@@ -16,12 +16,12 @@ fn walk_grid(input: &Grid<u8>) -> FastSet<Point> {
         let mut new_location = guard_location;
 
         loop {
-            let next = new_location + Point::from(guard_direction);
+            let next = new_location + guard_direction;
             if !input.contains(next) {
                 return positions;  // Found exit
             }
             if input[next] == b'#' {
-                guard_direction = guard_direction.clockwise();
+                guard_direction = guard_direction.turn_right();
                 break;
             }
             new_location = next;
@@ -35,10 +35,10 @@ pub fn part1(input: &Grid<u8>) -> u32 {
     walk_grid(input).len() as u32
 }
 
-fn is_loop(input: &Grid<u8>, start_position: Point, blocked_point: Point) -> bool {
+pub fn is_loop(input: &Grid<u8>, start_position: Point, blocked_point: Point) -> bool {
     let mut visited = FastSet::with_capacity(150);
     let mut guard_location = start_position;
-    let mut guard_direction = UP;
+    let mut guard_direction = Direction::North;
 
     visited.insert((guard_location, guard_direction));
 
@@ -46,12 +46,12 @@ fn is_loop(input: &Grid<u8>, start_position: Point, blocked_point: Point) -> boo
         let mut new_location = guard_location;
 
         loop {
-            let next = new_location + Point::from(guard_direction);
+            let next = new_location + guard_direction;
             if !input.contains(next) {
                 return false;
             }
             if input[next] == b'#' || next == blocked_point {
-                guard_direction = guard_direction.clockwise();
+                guard_direction = guard_direction.turn_right();
                 if visited.contains(&(new_location, guard_direction)) {
                     return true;
                 }
@@ -29,13 +29,11 @@ pub fn find_paths(grid: &Grid<u8>, distinct: bool, debug: bool) -> u32 {
 }
 
 fn dfs(grid: &Grid<u8>, seen: &mut Grid<Point>, start: Point, point: Point, distinct: bool) -> u32 {
-    ORTHOGONAL
-        .iter()
-        .fold(0, |total, &direction| {
-            let next = point + direction;
-            if grid.contains(next) && grid[next] == grid[point] + 1 && (distinct || seen[next] != start) {
+    grid.orthogonal_neighbours(point)
+        .fold(0, |total, (next, &height)| {
+            if height == grid[point] + 1 && (distinct || seen[next] != start) {
                 seen[next] = start;
-                total + if grid[next] == b'9' {
+                total + if height == b'9' {
                     1
                 } else {
                     dfs(grid, seen, start, next, distinct)
@@ -2,7 +2,7 @@ use crate::util::parse::*;
 
 pub fn parse(input: &str) -> Vec<Vec<u64>> {
     input
-        .split("\n\n")
+        .blocks()
         .map(|group| group.iter_unsigned().collect())
         .collect()
 }
@@ -1,9 +1,10 @@
 use crate::util::grid::*;
+use crate::util::parse::*;
 use crate::util::point::*;
 use std::mem::swap;
 
 pub fn parse(input: &str) -> (Grid<u8>, Vec<Point>) {
-    let (g, m) = input.split_once("\n\n").unwrap();
+    let [g, m] = input.sections().unwrap();
     let grid = Grid::parse(g);
     let moves: Vec<Point> = m
         .chars()
@@ -17,7 +18,7 @@ pub fn parse(input: &str) -> (Grid<u8>, Vec<Point>) {
 pub fn part1(input: &(Grid<u8>, Vec<Point>)) -> u32 {
     let (grid, moves) = input;
     let mut grid = grid.clone();
-    do_moves(&mut grid, &moves);
+    do_moves(&mut grid, moves);
     score_grid(&grid, b'O')
 }
 
@@ -51,7 +52,7 @@ pub fn move_object(grid: &mut Grid<u8>, pos: Point, dir: Point, object: u8) -> b
     }
 }
 
-pub fn do_moves(grid: &mut Grid<u8>, moves: &Vec<Point>) {
+pub fn do_moves(grid: &mut Grid<u8>, moves: &[Point]) {
     let mut robot = grid.find(b'@').unwrap();
     for &mv in moves {
         if move_object(grid, robot, mv, b'@') {
@@ -176,11 +177,11 @@ fn wide(
     *start += dir;
 }
 
-pub fn do_wide_moves(grid: &mut Grid<u8>, robot: &mut Point, moves: &Vec<Point>) {
+pub fn do_wide_moves(grid: &mut Grid<u8>, robot: &mut Point, moves: &[Point]) {
     let mut todo = Vec::new();
     let mut seen = grid.same_size_with(usize::MAX);
 
-    for (id, p) in moves.into_iter().enumerate() {
+    for (id, p) in moves.iter().enumerate() {
         match *p {
             LEFT => narrow(grid, robot, LEFT),
             RIGHT => narrow(grid, robot, RIGHT),
@@ -1,15 +1,16 @@
+use crate::util::direction::Direction;
 use crate::util::grid::*;
 use crate::util::hash::{FastMap, FastSet};
 use crate::util::point::*;
-use pathfinding::prelude::{astar_bag, AstarSolution};
+use crate::util::search::*;
 
 #[derive(Clone, Eq, Hash, PartialEq, Debug)]
 pub struct Reindeer {
     pub pos: Point,
-    pub dir: Point,
+    pub dir: Direction,
 }
 
-type Input = (Grid<u8>, AstarSolution<Reindeer>, u32);
+type Input = (Grid<u8>, ShortestPaths<Reindeer>, u32);
 
 pub fn parse(input: &str) -> Input {
     let grid = Grid::parse(input);
@@ -17,10 +18,10 @@ pub fn parse(input: &str) -> Input {
     let end = grid.find(b'E').unwrap();
     let reindeer = Reindeer {
         pos: start,
-        dir: RIGHT,
+        dir: Direction::East,
     };
-    let (solution, cost) = astar_bag(
-        &reindeer,
+    let (solution, cost) = astar_all(
+        reindeer,
         |r| get_successors(r, &grid),
         |r| r.pos.manhattan(end) as u32,
         |r| r.pos == end,
@@ -48,44 +49,34 @@ pub fn get_successors(r: &Reindeer, grid: &Grid<u8>) -> Vec<(Reindeer, u32)> {
 
     // we could just use turns here, i.e. same position but in different direction, but 
     // using the next positions is quicker as there are less steps overall by combining turn and move into a single step
-    let left = r.pos + r.dir.counter_clockwise();
+    let left = r.pos + r.dir.turn_left();
     if grid[left] != b'#' {
-        potential_positions.push((Reindeer { pos: left, dir: r.dir.counter_clockwise() }, 1001));
+        potential_positions.push((Reindeer { pos: left, dir: r.dir.turn_left() }, 1001));
     }
 
-    let right = r.pos + r.dir.clockwise();
+    let right = r.pos + r.dir.turn_right();
     if grid[right] != b'#' {
-        potential_positions.push((Reindeer { pos: right, dir: r.dir.clockwise() }, 1001));
+        potential_positions.push((Reindeer { pos: right, dir: r.dir.turn_right() }, 1001));
     }
 
-    let behind = r.pos - r.dir;
+    let behind = r.pos + r.dir.opposite();
     if grid[behind] != b'#' {
-        potential_positions.push((Reindeer { pos: behind, dir: r.dir.clockwise().clockwise() }, 2001));
+        potential_positions.push((Reindeer { pos: behind, dir: r.dir.opposite() }, 2001));
     }
 
     // println!("pos: {:?}, dir: {:?}, potential_positions: {:?}", r.pos, r.dir, potential_positions);
     potential_positions
 }
 
-pub fn all_reindeer_points(solution: AstarSolution<Reindeer>) -> FastSet<Point> {
-    solution.fold(FastSet::default(), |mut set, rs| {
-        set.extend(rs.iter().map(|r| r.pos));
-        set
-    })
+pub fn all_reindeer_points(solution: ShortestPaths<Reindeer>) -> FastSet<Point> {
+    solution.nodes().map(|r| r.pos).collect()
 }
 
 pub fn reindeer_path_to_string(grid: &Grid<u8>, path: &[Reindeer]) -> String {
     let mut result = grid.clone();
 
     for reindeer in path {
-        let dir_char = match reindeer.dir {
-            UP => b'^',
-            DOWN => b'v',
-            LEFT => b'<',
-            RIGHT => b'>',
-            _ => panic!("Invalid direction"),
-        };
-        result[reindeer.pos] = dir_char;
+        result[reindeer.pos] = reindeer.dir.to_arrow().expect("Invalid direction");
     }
 
     // put start and end markers back over top
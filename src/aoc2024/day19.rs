@@ -1,13 +1,12 @@
-use pathfinding::prelude::count_paths;
+use crate::util::parse::*;
+use crate::util::search::count_paths;
 
 const USE_PATHFINDING: bool = false;
 
 pub fn parse(input: &str) -> Vec<u64> {
-    let mut parts = input.split("\n\n");
+    let [towels, designs] = input.sections().unwrap();
     
-    let sequences: Vec<&str> = parts
-        .next()
-        .unwrap()
+    let sequences: Vec<&str> = towels
         .split(',')
         .map(|s| s.trim())
         .collect();
@@ -18,9 +17,7 @@ pub fn parse(input: &str) -> Vec<u64> {
         count_valid_combinations_dp
     };
     
-    parts
-        .next()
-        .unwrap()
+    designs
         .lines()
         .map(|input| count_fn(input, &sequences))
         .collect()
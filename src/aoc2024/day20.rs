@@ -1,6 +1,5 @@
 use crate::util::grid::*;
 use crate::util::point::*;
-use std::collections::VecDeque;
 use rayon::prelude::*;
 
 // find both solutions as we parse
@@ -9,62 +8,41 @@ pub fn parse(input: &str) -> (u32, u32) {
     count_improvements(&bfs_times, 20, 100)
 }
 
-// find the time it takes to reach each every point from start
-// walls will stay as time 0, which means they aren't on the path
-pub fn bfs_times(grid: &Grid<u8>) -> Grid<usize> {
+// find the time it takes to reach every point from start
+// walls stay as None, which means they aren't on the path
+pub fn bfs_times(grid: &Grid<u8>) -> Grid<Option<u32>> {
     let start = grid.find(b'S').unwrap();
-    let mut grid_bfs_time = grid.same_size_with(0_usize);
-    let mut queue = VecDeque::new();
-    
-    grid_bfs_time[start] = 1;
-    queue.push_back(start);
-    let mut step_time = 0;
-
-    // simple bfs
-    while let Some(current) = queue.pop_front() {
-        step_time += 1;
-        for &dir in ORTHOGONAL.iter() {
-            let next = current + dir;
-            if grid.contains(next) && grid[next] != b'#' && grid_bfs_time[next] == 0 {
-                queue.push_back(next);
-                // we need to offset since start time is 1, so we can check 0 value where not yet visited
-                grid_bfs_time[next] = step_time + 1;
-            }
-        }
-    }
-    
-    grid_bfs_time
+    grid.bfs_distances([start], &ORTHOGONAL, |&b| b != b'#')
 }
 
 pub fn count_improvements(
-    grid_bfs_time: &Grid<usize>,
+    grid_bfs_time: &Grid<Option<u32>>,
     max_range: i32,
-    min_improvement: usize,
+    min_improvement: u32,
 ) -> (u32, u32) {
     // get all the path points from the grid so we can iterate over them
     let path_points: Vec<_> = grid_bfs_time.points()
-        .filter(|&p| grid_bfs_time[p] > 0)
+        .filter_map(|p| grid_bfs_time[p].map(|time| (p, time)))
         .collect();
 
     let results: Vec<(u32, u32)> = path_points.par_iter()
-        .map(|&current| {
+        .map(|&(current, current_step_time)| {
             let mut improvements_p1 = 0;
             let mut improvements_p2 = 0;
             
             // look around the current point to see if we connect with somewhere else that's better than our improvement minimum
             for dy in -max_range..=max_range {
                 for dx in (-max_range + dy.abs())..=(max_range - dy.abs()) {
-                    let cheat_distance = (dx.abs() + dy.abs()) as usize;
+                    let cheat_distance = (dx.abs() + dy.abs()) as u32;
                     let next = current + Point::new(dx, dy);
                     // jump to next point if it's not on the path
-                    if !grid_bfs_time.contains(next) {
+                    let Some(&Some(next_step_time)) = grid_bfs_time.get(next) else {
                         continue;
-                    }
+                    };
 
-                    let next_step_time = grid_bfs_time[next];
                     // do we make enough of an improvement if we can get to the next point?
-                    // note: saturating_sub is used to avoid having to do "if next_step_time > current_step_time" and returns 0 for that case, as the types are usize we can't simply subtract
-                    if next_step_time.saturating_sub(grid_bfs_time[current]) >= cheat_distance + min_improvement {
+                    // note: saturating_sub is used to avoid having to do "if next_step_time > current_step_time" and returns 0 for that case, as the types are unsigned we can't simply subtract
+                    if next_step_time.saturating_sub(current_step_time) >= cheat_distance + min_improvement {
                         if cheat_distance == 2 {
                             improvements_p1 += 1;
                         }
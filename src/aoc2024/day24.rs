@@ -1,23 +1,22 @@
+use crate::util::parse::*;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 
 pub fn parse(input: &str) -> Circuit {
-    let (known_values, gates) = input.split_once("\n\n").unwrap();
+    let [known_values, gates] = input.sections().unwrap();
 
-    let known_values: HashMap<String, u16> = known_values.lines().map(|line| {
-        let (var, value) = line.split_once(": ").unwrap();
-        (var.to_string(), value.parse().unwrap())
-    }).collect();
+    let known_values: HashMap<String, u16> = Pattern::new("{wire}: {value}")
+        .parse_lines(known_values)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .into_iter()
+        .collect();
 
+    let gate = Pattern::new("{input} {op} {input} -> {output}");
     let gates: Vec<Gate> = gates.lines().map(|line| {
-        let (gate_str, output) = line.split_once(" -> ").unwrap();
-        let parts: Vec<&str> = gate_str.split_whitespace().collect();
-        
-        let (inputs, op) = match parts.len() {
-            3 => (vec![parts[0], parts[2]], parts[1]),  // Format: "x00 AND x01"
-            _ => panic!("Invalid gate format"),
-        };
+        let (a, op, b, output): (&str, &str, &str, &str) =
+            gate.parse(line).unwrap_or_else(|e| panic!("{e}"));
+        let inputs = [a, b];
 
         let operation = match op {
             "AND" => Operation::And,
@@ -1,4 +1,5 @@
 use crate::util::grid::*;
+use crate::util::parse::*;
 use crate::util::point::*;
 
 pub fn parse(input: &str) -> u32 {
@@ -7,12 +8,12 @@ pub fn parse(input: &str) -> u32 {
     let mut keys = Vec::with_capacity(250);
     let is_lock = |grid: &Grid<u8>| grid[ORIGIN] == b'#';
 
-    for pattern in input.split("\n\n") {
+    for pattern in input.blocks() {
         let grid = Grid::parse(pattern);
         if is_lock(&grid) {
-            locks.push(lock_lengths(&grid));
+            locks.push(pin_heights(&grid));
         } else {
-            keys.push(key_lengths(&grid));
+            keys.push(pin_heights(&grid));
         }
     }
 
@@ -25,24 +26,12 @@ pub fn parse(input: &str) -> u32 {
     }).sum()
 }
 
-fn key_lengths(pattern: &Grid<u8>) -> Vec<u32> {
-    (0..5).map(|x| {
-        let mut position = Point::new(x, 5);
-        while pattern[position] == b'#' {
-            position += UP;
-        }
-        (5 - position.y) as u32
-    }).collect()
-}
-
-fn lock_lengths(pattern: &Grid<u8>) -> Vec<u32> {
-    (0..5).map(|x| {
-        let mut position = Point::new(x, 1);
-        while pattern[position] == b'#' {
-            position += DOWN;
-        }
-        (position.y - 1) as u32
-    }).collect()
+// Both keys and locks have one full row that isn't part of a pin, so the height of each pin is
+// one less than the number of '#' in its column.
+fn pin_heights(pattern: &Grid<u8>) -> Vec<u32> {
+    pattern.columns()
+        .map(|column| column.filter(|&&b| b == b'#').count() as u32 - 1)
+        .collect()
 }
 
 pub fn part1(input: &u32) -> u32 {
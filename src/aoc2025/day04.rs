@@ -101,19 +101,8 @@ pub fn part2_old(input: &Grid<u8>) -> u32 {
 }
 
 fn has_at_least_4_neighbours(grid: &Grid<u8>, p: Point) -> bool {
-    let mut count = 0;
-
-    for &direction in DIAGONAL.iter() {
-        let x = p + direction;
-        if grid.contains(x) && grid[x] == b'@' {
-            count += 1;
-            if count == 4 {
-                return true; // early exit
-            }
-        }
-    }
-
-    false
+    // nth stops as soon as the 4th neighbour is found
+    grid.all_neighbours(p).filter(|&(_, &b)| b == b'@').nth(3).is_some()
 }
 
 pub fn find_removable(input: &Grid<u8>) -> Vec<Point> {
@@ -1,3 +1,6 @@
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point3::*;
 use std::cmp::Reverse;
 
 #[derive(Debug)]
@@ -90,18 +93,8 @@ impl DSU {
     }
 }
 
-pub fn parse_coords(input: &str) -> Vec<(i64, i64, i64)> {
-    input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|line| {
-            let mut parts = line.split(',');
-            let x = parts.next().unwrap().parse().unwrap();
-            let y = parts.next().unwrap().parse().unwrap();
-            let z = parts.next().unwrap().parse().unwrap();
-            (x, y, z)
-        })
-        .collect()
+pub fn parse_coords(input: &str) -> Vec<Point3> {
+    input.iter_signed().chunk::<3>().map(Point3::from).collect()
 }
 
 fn top3_product(dsu: &DSU) -> u64 {
@@ -120,7 +113,7 @@ fn top3_product(dsu: &DSU) -> u64 {
     sizes.iter().take(3).product::<usize>() as u64
 }
 
-pub fn solve_both(coordinates: &[(i64, i64, i64)], part1_limit: usize) -> (u64, u64) {
+pub fn solve_both(coordinates: &[Point3], part1_limit: usize) -> (u64, u64) {
     let n = coordinates.len();
 
     // build all unique pairs with squared distance
@@ -129,14 +122,7 @@ pub fn solve_both(coordinates: &[(i64, i64, i64)], part1_limit: usize) -> (u64,
 
     for i in 0..n {
         for j in i + 1..n {
-            let (x1, y1, z1) = coordinates[i];
-            let (x2, y2, z2) = coordinates[j];
-
-            let dx = x1 - x2;
-            let dy = y1 - y2;
-            let dz = z1 - z2;
-
-            let dist2 = (dx * dx + dy * dy + dz * dz) as u64;
+            let dist2 = coordinates[i].euclidean_squared(coordinates[j]) as u64;
             pairs.push((i, j, dist2));
         }
     }
@@ -165,9 +151,7 @@ pub fn solve_both(coordinates: &[(i64, i64, i64)], part1_limit: usize) -> (u64,
         // after this union, check if everything is in a single circuit
         let root = dsu.find(i);
         if dsu.size[root] as usize == n && part2_answer.is_none() {
-            let (x1, _, _) = coordinates[i];
-            let (x2, _, _) = coordinates[j];
-            part2_answer = Some((x1 as u64) * (x2 as u64));
+            part2_answer = Some((coordinates[i].x as u64) * (coordinates[j].x as u64));
             break; // fully connected, no need to process more pairs
         }
     }
@@ -1,7 +1,7 @@
-use pathfinding::prelude::bfs;
+use crate::util::search::bfs;
 // use crate::util::frac::Frac;
 use z3::{ast::Int, Optimize, SatResult};
-use regex::Regex;
+use crate::util::parse::*;
 
 #[derive(Debug, Clone)]
 pub struct Machine {
@@ -20,15 +20,11 @@ pub fn part2(input: &(u32, u32)) -> u32 {
 }
 
 pub fn parse_machine(line: &str) -> Machine {
-    let re = Regex::new(
-        r"^\s*\[([.#]+)\]\s*((?:\([\d,]+\)\s*)+)\{([\d,\s]+)\}\s*$"
-    ).unwrap();
+    let pattern = Pattern::new("[{lights}] {buttons} {{{joltages}}}");
+    let (lights_str, buttons_block, jolts_str): (&str, &str, &str) = pattern
+        .parse(line.trim())
+        .unwrap_or_else(|e| panic!("Invalid machine line: {e}"));
 
-    let caps = re
-        .captures(line)
-        .unwrap_or_else(|| panic!("Invalid machine line: {line}"));
-
-    let lights_str = caps.get(1).unwrap().as_str();
     let num_lights = lights_str.len() as u8;
 
     let mut target: u64 = 0;
@@ -40,22 +36,16 @@ pub fn parse_machine(line: &str) -> Machine {
         }
     }
 
-    let buttons_block = caps.get(2).unwrap().as_str();
-    let b_re = Regex::new(r"\(([\d,]+)\)").unwrap();
-
     let mut button_masks = Vec::new();
-    for bcap in b_re.captures_iter(buttons_block) {
-        let inner = bcap.get(1).unwrap().as_str();
+    for button in buttons_block.split_whitespace() {
         let mut mask = 0u64;
 
-        for num in inner.split(',') {
-            let idx: usize = num.parse().unwrap();
+        for idx in button.iter_unsigned::<usize>() {
             mask |= 1u64 << idx;
         }
         button_masks.push(mask);
     }
 
-    let jolts_str = caps.get(3).unwrap().as_str();
     let joltages: Vec<u32> = jolts_str
         .split(',')
         .map(|s| s.trim().parse::<u32>().unwrap())
@@ -90,7 +80,7 @@ pub fn parse(input: &str) -> (u32, u32) {
     (part1 as u32, part2 as u32)
 }
 
-// use pathfinding::prelude::bfs to search for the shortest path
+// use bfs to search for the shortest path
 // This was quite simple, using XOR as a simple state change on button press was nice
 pub fn min_light_presses(machine: &Machine) -> Option<usize> {
     let start: u64 = 0;
@@ -101,7 +91,7 @@ pub fn min_light_presses(machine: &Machine) -> Option<usize> {
     }
 
     let path = bfs(
-        &start,
+        start,
         |state| {
             // From a given state, you can press any button:
             // successor = state XOR button_mask
@@ -1,77 +1,98 @@
 use std::fmt::Write; // for write! on String
-use crate::util::hash::*;
+use crate::util::intern::*;
 
-pub fn parse(input: &str) -> FastMap<&str, Vec<&str>> {
-    let mut graph: FastMap<&str, Vec<&str>> = FastMap::new();
+pub struct Devices<'a> {
+    pub names: Interner<'a>,
+    /// Outputs of each device, indexed by id.
+    pub outputs: Vec<Vec<u32>>,
+}
+
+pub fn parse(input: &str) -> Devices<'_> {
+    // Every device name is 3 lowercase letters
+    let mut names = Interner::fixed_width(3);
+    let mut outputs: Vec<Vec<u32>> = Vec::new();
 
     for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
         let (from, rest) = line
             .split_once(':')
             .expect("Each line should look like `name: child1 child2 ...`");
 
-        let from = from.trim();
-        let children: Vec<&str> = rest.split_whitespace().collect();
-        graph.insert(from, children);
+        let from = names.intern(from.trim()) as usize;
+        let children: Vec<u32> = rest.split_whitespace().map(|name| names.intern(name)).collect();
+        outputs.resize(names.len(), Vec::new());
+        outputs[from] = children;
     }
 
-    graph
+    // Devices like "out" only ever appear as children
+    outputs.resize(names.len(), Vec::new());
+    Devices { names, outputs }
 }
 
-pub fn dfs<'a>(
-    node: &'a str,
-    target: &str,
+pub fn dfs(
+    node: u32,
+    target: u32,
     mask: u8,
-    graph: &'a FastMap<&'a str, Vec<&'a str>>,
-    memo: &mut FastMap<(&'a str, u8), u64>,
-    visit1: &str,
-    visit2: &str,
+    graph: &[Vec<u32>],
+    memo: &mut [[Option<u64>; 4]],
+    visit1: Option<u32>,
+    visit2: Option<u32>,
 ) -> u64 {
     // Update the mask based on current node
     let mut new_mask = mask;
-    if node == visit1 {
+    if Some(node) == visit1 {
         new_mask |= 1; // set bit 0
     }
-    if node == visit2 {
+    if Some(node) == visit2 {
         new_mask |= 2; // set bit 1
     }
 
-    let key = (node, new_mask);
-    if let Some(&v) = memo.get(&key) {
+    if let Some(v) = memo[node as usize][new_mask as usize] {
         return v;
     }
 
     if node == target {
         // Only count paths that have seen BOTH visit1 and visit2
         let res = if new_mask == 0b11 { 1 } else { 0 };
-        memo.insert(key, res);
+        memo[node as usize][new_mask as usize] = Some(res);
         return res;
     }
 
     let mut total = 0;
-    if let Some(neighbours) = graph.get(node) {
-        for &next in neighbours {
-            total += dfs(next, target, new_mask, graph, memo, visit1, visit2);
-        }
+    for &next in &graph[node as usize] {
+        total += dfs(next, target, new_mask, graph, memo, visit1, visit2);
     }
 
-    memo.insert(key, total);
+    memo[node as usize][new_mask as usize] = Some(total);
     total
 
 }
 
+/// Counts paths from `start` to `end`, only including paths through both of `visit` if given.
+pub fn count_paths(devices: &Devices<'_>, start: &str, end: &str, visit: Option<[&str; 2]>) -> u64 {
+    let (Some(start), Some(end)) = (devices.names.get(start), devices.names.get(end)) else {
+        return 0;
+    };
+    let mut memo = vec![[None; 4]; devices.names.len()];
+
+    match visit {
+        Some([visit1, visit2]) => {
+            let (visit1, visit2) = (devices.names.get(visit1), devices.names.get(visit2));
+            dfs(start, end, 0, &devices.outputs, &mut memo, visit1, visit2)
+        }
+        None => dfs(start, end, 3, &devices.outputs, &mut memo, None, None),
+    }
+}
 
-pub fn part1(input: &FastMap<&str, Vec<&str>>) -> u64 {
-    let mut memo_p1 = FastMap::new();
-    dfs("you", "out", 3, input, &mut memo_p1, "xxx", "xxx")
+pub fn part1(input: &Devices<'_>) -> u64 {
+    count_paths(input, "you", "out", None)
 }
 
-pub fn part2(input: &FastMap<&str, Vec<&str>>) -> u64 {
-    let mut memo_p2 = FastMap::new();
-    dfs("svr", "out", 0, input, &mut memo_p2, "fft", "dac")
+pub fn part2(input: &Devices<'_>) -> u64 {
+    count_paths(input, "svr", "out", Some(["fft", "dac"]))
 }
 
 pub fn to_graphviz(
-    graph: &FastMap<&str, Vec<&str>>,
+    graph: &Devices<'_>,
     start1: &str, // e.g. "you"
     start2: &str, // e.g. "svr"
     out: &str,
@@ -117,9 +138,9 @@ pub fn to_graphviz(
     );
 
     // --- Edges ---
-    for (from, tos) in graph {
-        for to in tos {
-            let _ = write!(s, "  \"{}\" -> \"{}\";\n", from, to);
+    for (from, tos) in graph.outputs.iter().enumerate() {
+        for &to in tos {
+            let _ = write!(s, "  \"{}\" -> \"{}\";\n", graph.names.name(from as u32), graph.names.name(to));
         }
     }
 
@@ -22,7 +22,7 @@ macro_rules! library {
 }
 
 library!(util "Utility modules to handle common recurring Advent of Code patterns."
-    ansi, bitset, bronkerbosch, frac, grid, hash, heap, integer, iter, math, md5, parse, point, slice, thread, tomita
+    ansi, bitset, bronkerbosch, compress, direction, frac, geometry, grid, grid3, hash, heap, integer, intern, interval, iter, math, md5, ocr, parse, point, point3, search, slice, sparse, thread, tomita
 );
 
 library!(aoc2015 "AOC 2015"
@@ -47,7 +47,7 @@ library!(aoc2025 "AOC 2025"
 );
 
 library!(vis2024 "Visualisations for AOC 2024"
-    day16
+    day06, day14, day15, day16
 );
 
 library!(vis2025 "Visualisations for AOC 2025"
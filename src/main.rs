@@ -169,7 +169,7 @@ macro_rules! viz {
 }
 
 viz!(vis2024
-    day16
+    day06, day14, day15, day16
 );
 
 viz!(vis2025
@@ -1,6 +1,36 @@
 //! Add `biterator` method that treats an integer as a set, iterating over each element where
 //! the respective bit is set. For example `1101` would return 0, 2 and 3.
+//!
+//! [`BitSet`] extends the same idea to any number of elements, storing a set of integers below a
+//! fixed capacity as one bit each in a `Vec<u64>`. Set operations work a word at a time and
+//! update the set in place, so recursive searches can avoid building new hash sets. A visited set
+//! for a [`Grid`] uses one bit per cell:
+//!
+//! ```
+//!   # use aoc::util::bitset::BitSet;
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::*;
+//!
+//!   let grid = Grid::parse("..#\n.#.\n...");
+//!   let mut visited = BitSet::new(grid.bytes.len());
+//!   let mut todo = vec![ORIGIN];
+//!
+//!   while let Some(point) = todo.pop() {
+//!       if visited.insert(grid.index_of(point)) {
+//!           let open = grid.orthogonal_neighbours(point).filter(|&(_, &b)| b == b'.');
+//!           todo.extend(open.map(|(next, _)| next));
+//!       }
+//!   }
+//!
+//!   assert_eq!(visited.len(), 7);
+//!   assert!(!visited.contains(grid.index_of(Point::new(2, 0))));
+//! ```
+//!
+//! [`Grid`]: crate::util::grid::Grid
+use crate::util::hash::*;
 use crate::util::integer::*;
+use std::fmt;
+use std::hash::Hash;
 
 pub trait BitOps<T> {
     fn biterator(self) -> Bitset<T>;
@@ -29,4 +59,201 @@ impl<T: Integer<T>> Iterator for Bitset<T> {
             Some(tz as usize)
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Empty set that can hold integers from 0 up to but not including `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        BitSet { words: vec![0; capacity.div_ceil(64)] }
+    }
+
+    /// Capacity rounded up to a whole number of words.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.words.len() * 64
+    }
+
+    /// Adds `index`, returning `true` if it wasn't already in the set.
+    #[inline]
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = (index / 64, 1 << (index % 64));
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// Removes `index`, returning `true` if it was in the set.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = (index / 64, 1 << (index % 64));
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// Always `false` for integers beyond the capacity.
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        self.words.get(index / 64).is_some_and(|&word| word & (1 << (index % 64)) != 0)
+    }
+
+    /// Number of integers in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    #[inline]
+    pub fn union_with(&mut self, other: &BitSet) {
+        self.combine(other, |a, b| a | b);
+    }
+
+    #[inline]
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        self.combine(other, |a, b| a & b);
+    }
+
+    #[inline]
+    pub fn difference_with(&mut self, other: &BitSet) {
+        self.combine(other, |a, b| a & !b);
+    }
+
+    /// Integers in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &word)| word.biterator().map(move |bit| i * 64 + bit))
+    }
+
+    #[inline]
+    fn combine(&mut self, other: &BitSet, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(self.words.len(), other.words.len(), "Sets must have the same capacity");
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Numbers each of `nodes` by its position and lists the neighbours of each one as a set of
+/// those numbers, for graph searches that work on [`BitSet`]s rather than the nodes themselves.
+pub fn adjacency<N, I>(nodes: &[N], neighbours: impl Fn(N) -> I) -> Vec<BitSet>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let bits: FastMap<N, usize> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+    nodes
+        .iter()
+        .map(|&node| {
+            let mut set = BitSet::new(nodes.len());
+            set.extend(neighbours(node).into_iter().map(|n| bits[&n]));
+            set
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_biterator() {
+        assert_eq!(0b1101_u8.biterator().collect::<Vec<_>>(), vec![0, 2, 3]);
+        assert_eq!(0_u64.biterator().count(), 0);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = BitSet::new(130);
+        assert_eq!(set.capacity(), 192);
+        assert!(set.is_empty());
+
+        assert!(set.insert(0));
+        assert!(set.insert(64));
+        assert!(set.insert(129));
+        assert!(!set.insert(64));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(129));
+        assert!(!set.contains(128));
+        assert!(!set.contains(1000));
+
+        assert!(set.remove(64));
+        assert!(!set.remove(64));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 129]);
+        assert_eq!(format!("{set:?}"), "{0, 129}");
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut a = BitSet::new(100);
+        let mut b = BitSet::new(100);
+        a.extend([1, 2, 3, 70, 99]);
+        b.extend([2, 3, 4, 99]);
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 70, 99]);
+
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        assert_eq!(intersection.iter().collect::<Vec<_>>(), vec![2, 3, 99]);
+
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        assert_eq!(difference.iter().collect::<Vec<_>>(), vec![1, 70]);
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let nodes = ['a', 'b', 'c'];
+        let edges = [('a', 'b'), ('b', 'c')];
+        let neighbours = adjacency(&nodes, |node| {
+            edges
+                .iter()
+                .filter(move |&&(x, y)| x == node || y == node)
+                .map(move |&(x, y)| if x == node { y } else { x })
+        });
+
+        let sets: Vec<Vec<_>> = neighbours.iter().map(|set| set.iter().collect()).collect();
+        assert_eq!(sets, vec![vec![1], vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sets must have the same capacity")]
+    fn test_mismatched_capacity() {
+        BitSet::new(64).union_with(&BitSet::new(65));
+    }
+}
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use petgraph::graphmap::{GraphMap,NodeTrait};
 use petgraph::Undirected;
 use petgraph::EdgeType;
+use std::marker::PhantomData;
+use crate::util::bitset::*;
 
 // https://github.com/horasal/clique-rust/blob/master/src/bronkerbosch.rs
 
@@ -11,22 +13,33 @@ use petgraph::EdgeType;
 /// connected is a symmetrical bolean matrix, N the number of nodes in the graph,
 /// values of the diagonal should be true.
 pub struct BronKerbosch<N: NodeTrait, E, Ty = Undirected> {
-    graph: GraphMap<N, E, Ty>,
-    max_cliques: Vec<HashSet<N>>
+    /// Nodes in the order of the bits in each set.
+    nodes: Vec<N>,
+    /// Neighbours of each node, as bits numbered like `nodes`.
+    neighbours: Vec<BitSet>,
+    max_cliques: Vec<HashSet<N>>,
+    phantom: PhantomData<(E, Ty)>,
 }
 
 impl<N: NodeTrait, E, Ty: EdgeType> BronKerbosch<N, E, Ty> {
     pub fn new(graphmap: GraphMap<N, E, Ty>) -> BronKerbosch<N, E, Ty> {
+        let nodes: Vec<N> = graphmap.nodes().collect();
+        let neighbours = adjacency(&nodes, |node| graphmap.neighbors(node));
+
         BronKerbosch {
-            graph: graphmap,
-            max_cliques: Vec::new()
+            nodes,
+            neighbours,
+            max_cliques: Vec::new(),
+            phantom: PhantomData,
         }
     }
 
     pub fn compute(&mut self) {
-        let p = self.graph.nodes().collect::<HashSet<N>>();
-        let r = HashSet::new();
-        let x = HashSet::new();
+        let n = self.nodes.len();
+        let mut p = BitSet::new(n);
+        p.extend(0..n);
+        let r = BitSet::new(n);
+        let x = BitSet::new(n);
         self.bronkerbosch(p, r, x);
     }
 
@@ -35,29 +48,29 @@ impl<N: NodeTrait, E, Ty: EdgeType> BronKerbosch<N, E, Ty> {
     }
 
 
-    fn bronkerbosch(&mut self, p: HashSet<N>, r: HashSet<N>, x: HashSet<N>) {
-        let mut p_fp = p.clone();
-        let mut x_fp = x.clone();
-
+    fn bronkerbosch(&mut self, p: BitSet, r: BitSet, x: BitSet) {
         if p.is_empty() {
             if x.is_empty() {
-                self.max_cliques.push(r.clone());
+                self.max_cliques.push(r.iter().map(|v| self.nodes[v]).collect());
             }
             return;
         }
 
-        for v in p.iter() {
-            let v_neighbours = self.graph.neighbors(v.clone()).collect::<HashSet<N>>();
+        let mut p_fp = p.clone();
+        let mut x_fp = x;
 
-            let p_intersect_v_neighbors = p_fp.intersection(&v_neighbours).cloned().collect();
+        for v in p.iter() {
+            let mut p_intersect_v_neighbors = p_fp.clone();
+            p_intersect_v_neighbors.intersect_with(&self.neighbours[v]);
             let mut r_union_v = r.clone();
-            r_union_v.insert(v.clone());
-            let x_intersect_v_neighbors = x_fp.intersection(&v_neighbours).cloned().collect();
+            r_union_v.insert(v);
+            let mut x_intersect_v_neighbors = x_fp.clone();
+            x_intersect_v_neighbors.intersect_with(&self.neighbours[v]);
 
             self.bronkerbosch(p_intersect_v_neighbors, r_union_v, x_intersect_v_neighbors);
 
             p_fp.remove(v);
-            x_fp.insert(*v);
+            x_fp.insert(v);
         }
 
     }
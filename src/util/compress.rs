@@ -0,0 +1,157 @@
+//! Coordinate compression, for puzzles set in a huge space where only a few coordinates matter.
+//!
+//! The interesting x and y values, usually the edges of rectangles or the vertices of a polygon,
+//! become breakpoints. Consecutive breakpoints bound a compressed cell, so a [`Grid`] with one
+//! cell per gap between breakpoints covers the whole space between the first and last
+//! breakpoint. Each cell is half open, including its top left edges but not its bottom right.
+//!
+//! Anything that is the same across a whole cell, for example whether it is covered by a set of
+//! rectangles, can then be computed once per cell and weighted by the cell's area:
+//!
+//! ```
+//!   # use aoc::util::compress::Compression;
+//!   # use aoc::util::point::*;
+//!
+//!   // Two overlapping squares, [0, 10) x [0, 10) and [5, 1000) x [5, 1000)
+//!   let squares = [(Point64::new(0, 0), Point64::new(10, 10)), (Point64::new(5, 5), Point64::new(1000, 1000))];
+//!   let compression = Compression::new(
+//!       squares.iter().flat_map(|(a, b)| [a.x, b.x]),
+//!       squares.iter().flat_map(|(a, b)| [a.y, b.y]),
+//!   );
+//!
+//!   let mut covered = compression.grid(0);
+//!   for &(from, to) in &squares {
+//!       for cell in compression.cells_between(from, to) {
+//!           covered[cell] += 1;
+//!       }
+//!   }
+//!
+//!   assert_eq!(covered.width, 3);
+//!   assert_eq!(compression.weighted_sum(&covered, |&n| (n > 0) as i64), 100 + 995 * 995 - 25);
+//!   assert_eq!(compression.weighted_sum(&covered, |&n| (n > 1) as i64), 25);
+//! ```
+//!
+//! [`Grid`]: crate::util::grid::Grid
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub struct Compression {
+    /// Sorted unique x breakpoints.
+    pub xs: Vec<i64>,
+    /// Sorted unique y breakpoints.
+    pub ys: Vec<i64>,
+}
+
+impl Compression {
+    pub fn new(xs: impl IntoIterator<Item = i64>, ys: impl IntoIterator<Item = i64>) -> Self {
+        let mut xs: Vec<_> = xs.into_iter().collect();
+        let mut ys: Vec<_> = ys.into_iter().collect();
+
+        xs.sort_unstable();
+        xs.dedup();
+        ys.sort_unstable();
+        ys.dedup();
+
+        Compression { xs, ys }
+    }
+
+    /// Grid with one cell for every gap between breakpoints.
+    pub fn grid<T: Copy>(&self, value: T) -> Grid<T> {
+        let width = self.xs.len().saturating_sub(1) as i32;
+        let height = self.ys.len().saturating_sub(1) as i32;
+        Grid::new(width, height, value)
+    }
+
+    /// Position of the breakpoint `point` among the breakpoints, which is also the cell whose
+    /// top left corner it is, or `None` if either coordinate isn't a breakpoint. The last
+    /// breakpoints in each direction have an index but no cell.
+    pub fn index(&self, point: Point64) -> Option<Point> {
+        let x = self.xs.binary_search(&point.x).ok()?;
+        let y = self.ys.binary_search(&point.y).ok()?;
+        Some(Point::new(x as i32, y as i32))
+    }
+
+    /// Cell containing any point between the first and last breakpoints.
+    pub fn cell_containing(&self, point: Point64) -> Option<Point> {
+        let x = self.xs.partition_point(|&x| x <= point.x).checked_sub(1)?;
+        let y = self.ys.partition_point(|&y| y <= point.y).checked_sub(1)?;
+        (x + 1 < self.xs.len() && y + 1 < self.ys.len()).then(|| Point::new(x as i32, y as i32))
+    }
+
+    /// Cells covering the half open rectangle from `from` up to but not including `to`, where
+    /// both corners are breakpoints.
+    pub fn cells_between(&self, from: Point64, to: Point64) -> impl Iterator<Item = Point> {
+        let from = self.index(from).expect("Corner must be a breakpoint");
+        let to = self.index(to).expect("Corner must be a breakpoint");
+        (from.y..to.y).flat_map(move |y| (from.x..to.x).map(move |x| Point::new(x, y)))
+    }
+
+    /// Top left corner of a cell in the original coordinates.
+    #[inline]
+    pub fn origin(&self, cell: Point) -> Point64 {
+        Point64::new(self.xs[cell.x as usize], self.ys[cell.y as usize])
+    }
+
+    /// Width and height of a cell in the original coordinates.
+    #[inline]
+    pub fn size(&self, cell: Point) -> Point64 {
+        let (x, y) = (cell.x as usize, cell.y as usize);
+        Point64::new(self.xs[x + 1] - self.xs[x], self.ys[y + 1] - self.ys[y])
+    }
+
+    #[inline]
+    pub fn area(&self, cell: Point) -> i64 {
+        let size = self.size(cell);
+        size.x * size.y
+    }
+
+    /// Sum of `weight` over every cell, multiplied by the area that the cell stands for.
+    pub fn weighted_sum<T>(&self, grid: &Grid<T>, mut weight: impl FnMut(&T) -> i64) -> i64 {
+        grid.points().map(|cell| weight(&grid[cell]) * self.area(cell)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakpoints() {
+        let compression = Compression::new([10, -5, 10, 1_000_000_000_000], [3, 0]);
+
+        assert_eq!(compression.xs, vec![-5, 10, 1_000_000_000_000]);
+        assert_eq!(compression.ys, vec![0, 3]);
+
+        let grid = compression.grid(());
+        assert_eq!((grid.width, grid.height), (2, 1));
+        assert_eq!(compression.area(Point::new(0, 0)), 45);
+        assert_eq!(compression.size(Point::new(1, 0)), Point64::new(999_999_999_990, 3));
+        assert_eq!(compression.origin(Point::new(1, 0)), Point64::new(10, 0));
+    }
+
+    #[test]
+    fn test_lookups() {
+        let compression = Compression::new([0, 4, 10], [0, 2]);
+
+        assert_eq!(compression.index(Point64::new(4, 0)), Some(Point::new(1, 0)));
+        assert_eq!(compression.index(Point64::new(10, 2)), Some(Point::new(2, 1)));
+        assert_eq!(compression.index(Point64::new(5, 0)), None);
+
+        assert_eq!(compression.cell_containing(Point64::new(0, 0)), Some(ORIGIN));
+        assert_eq!(compression.cell_containing(Point64::new(4, 1)), Some(Point::new(1, 0)));
+        assert_eq!(compression.cell_containing(Point64::new(9, 1)), Some(Point::new(1, 0)));
+        // Cells are half open, so the last breakpoints are outside every cell
+        assert_eq!(compression.cell_containing(Point64::new(10, 1)), None);
+        assert_eq!(compression.cell_containing(Point64::new(-1, 1)), None);
+        assert_eq!(compression.cell_containing(Point64::new(3, 2)), None);
+    }
+
+    #[test]
+    fn test_cells_between() {
+        let compression = Compression::new([0, 1, 2, 3], [0, 5, 6]);
+        let cells: Vec<_> = compression.cells_between(Point64::new(1, 0), Point64::new(3, 6)).collect();
+
+        assert_eq!(cells, vec![Point::new(1, 0), Point::new(2, 0), Point::new(1, 1), Point::new(2, 1)]);
+        assert_eq!(compression.cells_between(ORIGIN.into(), ORIGIN.into()).count(), 0);
+    }
+}
@@ -0,0 +1,196 @@
+//! Compass headings, for puzzles that track which way something is facing.
+//!
+//! Storing a heading as a [`Point`] means every turn or lookup has to handle points that aren't
+//! unit vectors. A [`Direction`] can only ever be one of the 8 compass points, so turning and
+//! indexing are total:
+//!
+//! ```
+//!   # use aoc::util::direction::Direction::{self, *};
+//!   # use aoc::util::point::*;
+//!
+//!   let heading = Direction::try_from(b'^').unwrap();
+//!   assert_eq!(heading, North);
+//!   assert_eq!(heading.turn_right(), East);
+//!   assert_eq!(heading.turn_left().opposite(), East);
+//!   assert_eq!(ORIGIN + heading, UP);
+//!   assert_eq!(West.to_arrow(), Some(b'<'));
+//! ```
+//!
+//! [`index`] gives each heading a dense index for visited-state arrays. The cardinal
+//! directions come first, so puzzles that never move diagonally only need 4 slots.
+//!
+//! [`Point`]: crate::util::point::Point
+//! [`index`]: Direction::index
+use crate::util::point::*;
+use std::ops::Add;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// Clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+    /// Clockwise from north, including diagonals.
+    pub const ALL: [Direction; 8] =
+        [North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest];
+
+    /// Dense index from 0 to 7, with the cardinal directions numbered 0 to 3 clockwise from
+    /// north in the same order as [`Point::to_index`].
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns 90 degrees clockwise.
+    #[inline]
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+            NorthEast => SouthEast,
+            SouthEast => SouthWest,
+            SouthWest => NorthWest,
+            NorthWest => NorthEast,
+        }
+    }
+
+    /// Turns 90 degrees counter clockwise.
+    #[inline]
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The `^`, `>`, `v` or `<` arrow for cardinal directions, or `None` for diagonals.
+    #[inline]
+    pub fn to_arrow(self) -> Option<u8> {
+        match self {
+            North => Some(b'^'),
+            East => Some(b'>'),
+            South => Some(b'v'),
+            West => Some(b'<'),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    #[inline]
+    fn from(direction: Direction) -> Self {
+        match direction {
+            North => UP,
+            East => RIGHT,
+            South => DOWN,
+            West => LEFT,
+            NorthEast => UP_RIGHT,
+            SouthEast => DOWN_RIGHT,
+            SouthWest => DOWN_LEFT,
+            NorthWest => UP_LEFT,
+        }
+    }
+}
+
+/// Fails with the original point if it isn't one of the 8 unit steps.
+impl TryFrom<Point> for Direction {
+    type Error = Point;
+
+    #[inline]
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Direction::ALL.into_iter().find(|&d| Point::from(d) == point).ok_or(point)
+    }
+}
+
+/// Accepts the same arrow and `UDLR` bytes as `Point::from`, failing with the original byte
+/// for anything else.
+impl TryFrom<u8> for Direction {
+    type Error = u8;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'^' | b'U' => Ok(North),
+            b'>' | b'R' => Ok(East),
+            b'v' | b'D' => Ok(South),
+            b'<' | b'L' => Ok(West),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, rhs: Direction) -> Point {
+        self + Point::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            let point = Point::from(direction);
+
+            assert_eq!(Point::from(direction.turn_right()), point.clockwise());
+            assert_eq!(Point::from(direction.turn_left()), point.counter_clockwise());
+            assert_eq!(Point::from(direction.opposite()), ORIGIN - point);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        let mut seen = [false; 8];
+        for direction in Direction::ALL {
+            seen[direction.index()] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        for direction in Direction::CARDINAL {
+            assert_eq!(direction.index(), Point::from(direction).to_index());
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(Point::from(direction)), Ok(direction));
+        }
+        assert_eq!(Direction::try_from(Point::new(2, 0)), Err(Point::new(2, 0)));
+        assert_eq!(Direction::try_from(ORIGIN), Err(ORIGIN));
+
+        for byte in *b"^v<>UDLR" {
+            let direction = Direction::try_from(byte).unwrap();
+            assert_eq!(Point::from(direction), Point::from(byte));
+        }
+        for direction in Direction::CARDINAL {
+            assert_eq!(Direction::try_from(direction.to_arrow().unwrap()), Ok(direction));
+        }
+        assert_eq!(NorthEast.to_arrow(), None);
+        assert_eq!(Direction::try_from(b'x'), Err(b'x'));
+    }
+}
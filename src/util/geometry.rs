@@ -0,0 +1,283 @@
+//! Exact integer geometry on polygons whose vertices are lattice points.
+//!
+//! Polygons are slices of vertices in order, either clockwise or counter clockwise, with an
+//! implicit edge from the last vertex back to the first. Every function accepts either [`Point`]
+//! or [`Point64`] and takes cross products in `i128`, so there is no floating point error. Any
+//! [`Point`] is safe, while [`Point64`] coordinates must stay within ±2^60 and the doubled area
+//! must fit in an `i64`.
+//!
+//! The [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula) gives the area of a
+//! polygon and [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem) relates it to the
+//! number of lattice points inside, a common way to count the tiles enclosed by a loop:
+//!
+//! ```
+//!   # use aoc::util::geometry::*;
+//!   # use aoc::util::point::*;
+//!
+//!   let square = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)];
+//!
+//!   assert_eq!(double_area(&square), 32);
+//!   assert_eq!(boundary_points(&square), 16);
+//!   assert_eq!(interior_points(&square), 9);
+//!   assert_eq!(point_in_polygon(Point::new(2, 4), &square), Location::Boundary);
+//! ```
+//!
+//! [`Point`]: crate::util::point::Point
+//! [`Point64`]: crate::util::point::Point64
+use crate::util::math::*;
+use crate::util::point::*;
+
+/// Where a point lies relative to a polygon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Twice the area of the polygon. Doubling keeps the result exact, as the area of a lattice
+/// polygon can end in a half.
+pub fn double_area<P: Copy + Into<Point64>>(polygon: &[P]) -> i64 {
+    let sum: i128 = edges(polygon).map(|(a, b)| cross(a, b)).sum();
+    sum.unsigned_abs() as i64
+}
+
+/// Number of lattice points on the edges of the polygon.
+pub fn boundary_points<P: Copy + Into<Point64>>(polygon: &[P]) -> i64 {
+    edges(polygon).map(|(a, b)| (b.x - a.x).abs().gcd((b.y - a.y).abs())).sum()
+}
+
+/// Number of lattice points strictly inside the polygon, from Pick's theorem
+/// `A = i + b / 2 - 1`.
+pub fn interior_points<P: Copy + Into<Point64>>(polygon: &[P]) -> i64 {
+    (double_area(polygon) - boundary_points(polygon) + 2) / 2
+}
+
+pub fn point_in_polygon<P: Copy + Into<Point64>>(point: P, polygon: &[P]) -> Location {
+    locate(point.into(), polygon, 1)
+}
+
+/// Whether two closed line segments share at least one point, including touching at an end
+/// or overlapping along the same line.
+pub fn segments_intersect<P: Copy + Into<Point64>>(a1: P, a2: P, b1: P, b2: P) -> bool {
+    let (a1, a2, b1, b2) = (a1.into(), a2.into(), b1.into(), b2.into());
+    let d1 = orientation(b1, b2, a1);
+    let d2 = orientation(b1, b2, a2);
+    let d3 = orientation(a1, a2, b1);
+    let d4 = orientation(a1, a2, b2);
+
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+
+    (d1 == 0 && on_segment(b1, b2, a1))
+        || (d2 == 0 && on_segment(b1, b2, a2))
+        || (d3 == 0 && on_segment(a1, a2, b1))
+        || (d4 == 0 && on_segment(a1, a2, b2))
+}
+
+/// Whether the axis aligned rectangle with opposite corners `corner1` and `corner2` lies
+/// entirely inside the rectilinear `polygon`, with edges allowed to touch. Every edge of the
+/// polygon must be horizontal or vertical.
+pub fn rectangle_in_polygon<P: Copy + Into<Point64>>(corner1: P, corner2: P, polygon: &[P]) -> bool {
+    let (c1, c2) = (corner1.into(), corner2.into());
+    let min = Point64::new(c1.x.min(c2.x), c1.y.min(c2.y));
+    let max = Point64::new(c1.x.max(c2.x), c1.y.max(c2.y));
+
+    debug_assert!(
+        edges(polygon).all(|(a, b)| a.x == b.x || a.y == b.y),
+        "Polygon must be rectilinear"
+    );
+
+    if min.x < max.x && min.y < max.y {
+        // If no edge passes through the open interior of the rectangle then the interior is
+        // either entirely inside or entirely outside, so testing the centre is enough.
+        let crosses = edges(polygon).any(|(a, b)| {
+            let (lo, hi) = (Point64::new(a.x.min(b.x), a.y.min(b.y)), Point64::new(a.x.max(b.x), a.y.max(b.y)));
+            if lo.x == hi.x {
+                min.x < lo.x && lo.x < max.x && lo.y.max(min.y) < hi.y.min(max.y)
+            } else {
+                min.y < lo.y && lo.y < max.y && lo.x.max(min.x) < hi.x.min(max.x)
+            }
+        });
+        !crosses && locate(min + max, polygon, 2) != Location::Outside
+    } else {
+        // A line or single point. Vertices split it into pieces that are each entirely inside,
+        // on the boundary or outside, so check the ends of every piece and its midpoint.
+        let horizontal = min.x < max.x;
+        let (from, to) = if horizontal { (min.x, max.x) } else { (min.y, max.y) };
+
+        let mut stops: Vec<_> = polygon
+            .iter()
+            .map(|&p| {
+                let p = p.into();
+                if horizontal { p.x } else { p.y }
+            })
+            .filter(|&c| from < c && c < to)
+            .chain([from, to])
+            .collect();
+        stops.sort_unstable();
+        stops.dedup();
+
+        let along = |doubled: i64| {
+            if horizontal {
+                Point64::new(doubled, min.y * 2)
+            } else {
+                Point64::new(min.x * 2, doubled)
+            }
+        };
+
+        stops.iter().all(|&c| locate(along(c * 2), polygon, 2) != Location::Outside)
+            && stops.windows(2).all(|w| locate(along(w[0] + w[1]), polygon, 2) != Location::Outside)
+    }
+}
+
+/// Locates `point` relative to `polygon` scaled up by `scale`, so that points halfway between
+/// lattice points can be tested exactly.
+fn locate<P: Copy + Into<Point64>>(point: Point64, polygon: &[P], scale: i64) -> Location {
+    let mut inside = false;
+
+    for (a, b) in edges(polygon) {
+        let (a, b) = (a * scale, b * scale);
+
+        if orientation(a, b, point) == 0 && on_segment(a, b, point) {
+            return Location::Boundary;
+        }
+
+        // Cast a ray towards positive x and count the edges that it crosses. The half open
+        // comparison on y counts a vertex exactly once.
+        if (a.y > point.y) != (b.y > point.y) {
+            let side = orientation(a, b, point);
+            if (b.y > a.y) == (side > 0) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside { Location::Inside } else { Location::Outside }
+}
+
+fn edges<P: Copy + Into<Point64>>(polygon: &[P]) -> impl Iterator<Item = (Point64, Point64)> + '_ {
+    let n = polygon.len();
+    (0..n).map(move |i| (polygon[i].into(), polygon[(i + 1) % n].into()))
+}
+
+#[inline]
+fn cross(a: Point64, b: Point64) -> i128 {
+    a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128
+}
+
+/// Sign of the turn from `a` to `b` to `c`, zero when the three points are collinear.
+#[inline]
+fn orientation(a: Point64, b: Point64, c: Point64) -> i128 {
+    cross(b - a, c - a).signum()
+}
+
+/// Whether `p`, already known to be collinear with `a` and `b`, lies between them.
+#[inline]
+fn on_segment(a: Point64, b: Point64, p: Point64) -> bool {
+    a.x.min(b.x) <= p.x && p.x <= a.x.max(b.x) && a.y.min(b.y) <= p.y && p.y <= a.y.max(b.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An L shape, listed clockwise in screen coordinates.
+    //
+    //   #####.
+    //   #####.
+    //   ##....
+    //   ##....
+    const L_SHAPE: [Point; 6] = [
+        Point::new(0, 0),
+        Point::new(4, 0),
+        Point::new(4, 1),
+        Point::new(1, 1),
+        Point::new(1, 3),
+        Point::new(0, 3),
+    ];
+
+    #[test]
+    fn test_area_and_pick() {
+        assert_eq!(double_area(&L_SHAPE), 12);
+        assert_eq!(boundary_points(&L_SHAPE), 14);
+        assert_eq!(interior_points(&L_SHAPE), 0);
+
+        // Winding order doesn't matter
+        let mut reversed = L_SHAPE;
+        reversed.reverse();
+        assert_eq!(double_area(&reversed), 12);
+
+        // Diagonal edges with a half unit area
+        let triangle = [Point::new(0, 0), Point::new(3, 1), Point::new(1, 3)];
+        assert_eq!(double_area(&triangle), 8);
+        assert_eq!(boundary_points(&triangle), 4);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let big = 2_000_000_000;
+        let square = [Point64::new(0, 0), Point64::new(big, 0), Point64::new(big, big), Point64::new(0, big)];
+
+        assert_eq!(double_area(&square), 2 * big * big);
+        assert_eq!(point_in_polygon(Point64::new(big - 1, 1), &square), Location::Inside);
+        assert_eq!(point_in_polygon(Point64::new(big + 1, 1), &square), Location::Outside);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let locate = |x, y| point_in_polygon(Point::new(x, y), &L_SHAPE);
+
+        assert_eq!(locate(0, 0), Location::Boundary);
+        assert_eq!(locate(2, 1), Location::Boundary);
+        assert_eq!(locate(1, 2), Location::Boundary);
+        assert_eq!(locate(2, 2), Location::Outside);
+        assert_eq!(locate(5, 0), Location::Outside);
+        assert_eq!(locate(-1, 1), Location::Outside);
+
+        let square = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)];
+        let diamond = [Point::new(2, 0), Point::new(4, 2), Point::new(2, 4), Point::new(0, 2)];
+        assert_eq!(point_in_polygon(Point::new(2, 2), &square), Location::Inside);
+        // Rays along y = 2 pass straight through the vertices at (0, 2) and (4, 2)
+        assert_eq!(point_in_polygon(Point::new(2, 2), &diamond), Location::Inside);
+        assert_eq!(point_in_polygon(Point::new(-1, 2), &diamond), Location::Outside);
+        assert_eq!(point_in_polygon(Point::new(3, 3), &diamond), Location::Boundary);
+    }
+
+    #[test]
+    fn test_segments_intersect() {
+        let p = Point::new;
+
+        assert!(segments_intersect(p(0, 0), p(4, 4), p(0, 4), p(4, 0)));
+        assert!(!segments_intersect(p(0, 0), p(1, 1), p(0, 4), p(4, 0)));
+        // Touching at an end
+        assert!(segments_intersect(p(0, 0), p(2, 2), p(2, 2), p(5, 0)));
+        assert!(segments_intersect(p(0, 0), p(4, 0), p(2, 0), p(2, 3)));
+        // Collinear
+        assert!(segments_intersect(p(0, 0), p(4, 0), p(3, 0), p(6, 0)));
+        assert!(!segments_intersect(p(0, 0), p(2, 0), p(3, 0), p(6, 0)));
+        // Parallel
+        assert!(!segments_intersect(p(0, 0), p(4, 0), p(0, 1), p(4, 1)));
+    }
+
+    #[test]
+    fn test_rectangle_in_polygon() {
+        let inside = |x1, y1, x2, y2| rectangle_in_polygon(Point::new(x1, y1), Point::new(x2, y2), &L_SHAPE);
+
+        assert!(inside(0, 0, 4, 1));
+        assert!(inside(1, 3, 0, 0));
+        assert!(!inside(0, 0, 4, 3));
+        assert!(!inside(0, 0, 2, 2));
+
+        // Lines and points along the boundary or through the interior
+        assert!(inside(0, 1, 4, 1));
+        assert!(inside(1, 0, 1, 3));
+        assert!(inside(0, 2, 1, 2));
+        assert!(!inside(0, 2, 2, 2));
+        assert!(!inside(2, 0, 2, 3));
+        assert!(inside(4, 0, 4, 0));
+        assert!(!inside(4, 3, 4, 3));
+    }
+}
@@ -21,11 +21,82 @@
 //! creates a grid of the same size, that can be used for in BFS algorithms for tracking visited
 //! location or for tracking cost in Djikstra.
 //!
+//! [`parse_with`] maps each byte to a different cell type, for example digits to their values.
+//! Rows must all be the same length, otherwise indexing would silently drift into the wrong
+//! row. [`try_parse_with`] returns a [`GridError`] for ragged input instead of panicking, or
+//! pads short rows when editors have stripped trailing spaces:
+//!
+//! ```
+//!   # use aoc::util::grid::{Grid, Ragged};
+//!   # use aoc::util::point::Point;
+//!
+//!   let digits = Grid::parse_with("12\n34", |b| (b - b'0') as u32);
+//!   assert_eq!(digits[Point::new(1, 1)], 4);
+//!
+//!   let padded = Grid::try_parse_with("abc\nd", Ragged::Pad(b' '), |b| b).unwrap();
+//!   assert_eq!(padded.width, 3);
+//!   assert_eq!(padded[Point::new(2, 1)], b' ');
+//!
+//!   assert!(Grid::try_parse_with("abc\nd", Ragged::Reject, |b| b).is_err());
+//! ```
+//!
+//! Geometric transforms [`rotate_cw`], [`rotate_ccw`], [`transpose`], [`flip_h`] and [`flip_v`]
+//! return new grids, so searches in every direction can reuse code written for one direction.
+//! [`rows`] and [`columns`] iterate over lines of cells and [`window`] borrows a rectangular
+//! [`SubGrid`] that is indexed relative to its own top left corner.
+//!
+//! Indexing with a point outside the grid is a bug and panics in debug builds. Use [`get`] or
+//! [`get_mut`] when a point may be out of bounds, or [`orthogonal_neighbours`] and
+//! [`all_neighbours`] to visit only the adjacent points that lie inside the grid.
+//!
+//! [`bfs_distances`] finds the number of steps to every reachable point from one or more starting
+//! points, and [`path_to`] walks the resulting distance field back to recover a shortest path:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::*;
+//!
+//!   let maze = Grid::parse("S.#\n#..\n..E");
+//!   let start = maze.find(b'S').unwrap();
+//!   let end = maze.find(b'E').unwrap();
+//!
+//!   let distances = maze.bfs_distances([start], &ORTHOGONAL, |&b| b != b'#');
+//!   assert_eq!(distances[end], Some(4));
+//!   assert_eq!(distances[Point::new(0, 2)], Some(4));
+//!   assert_eq!(distances.path_to(end, &ORTHOGONAL).unwrap().len(), 5);
+//! ```
+//!
+//! [`regions`] labels connected areas of matching cells, measuring the area, perimeter and
+//! number of sides of each one.
+//!
+//! Puzzles set on a board whose edges wrap around can use [`Torus`], which wraps every point
+//! used for indexing or neighbour iteration back onto the grid.
+//!
 //! [`Point`]: crate::util::point
+//! [`get`]: Grid::get
+//! [`get_mut`]: Grid::get_mut
+//! [`orthogonal_neighbours`]: Grid::orthogonal_neighbours
+//! [`all_neighbours`]: Grid::all_neighbours
+//! [`rotate_cw`]: Grid::rotate_cw
+//! [`rotate_ccw`]: Grid::rotate_ccw
+//! [`transpose`]: Grid::transpose
+//! [`flip_h`]: Grid::flip_h
+//! [`flip_v`]: Grid::flip_v
+//! [`rows`]: Grid::rows
+//! [`columns`]: Grid::columns
+//! [`window`]: Grid::window
+//! [`bfs_distances`]: Grid::bfs_distances
+//! [`path_to`]: Grid::path_to
+//! [`regions`]: Grid::regions
 //! [`parse`]: Grid::parse
+//! [`parse_with`]: Grid::parse_with
+//! [`try_parse_with`]: Grid::try_parse_with
 //! [`same_size_with`]: Grid::same_size_with
 use crate::util::hash::*;
 use crate::util::point::*;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
 use std::ops::{Index, IndexMut};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -35,7 +106,38 @@ pub struct Grid<T> {
     pub bytes: Vec<T>,
 }
 
+/// How [`Grid::try_parse_with`] handles rows that are not all the same length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ragged {
+    /// Fail with [`GridError::RaggedRow`] on the first row that differs from the first row.
+    Reject,
+    /// Make the grid as wide as the longest row, filling short rows with this byte.
+    Pad(u8),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// `row` is zero based, but displayed as a one based line number.
+    RaggedRow { row: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GridError::RaggedRow { row, expected, found } => write!(
+                f,
+                "line {} is {found} bytes wide but the first line is {expected} bytes wide",
+                row + 1
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
 impl Grid<u8> {
+    /// Rows are assumed to all be the same length and are not checked. Use
+    /// [`try_parse_with`](Grid::try_parse_with) for input that may be ragged.
     #[inline]
     pub fn parse(input: &str) -> Self {
         let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
@@ -87,6 +189,43 @@ impl<T: Copy> Grid<T> {
 }
 
 impl<T> Grid<T> {
+    /// Parses a rectangular block of text, converting each byte to a cell with `f`.
+    /// Panics if the rows are not all the same length.
+    pub fn parse_with(input: &str, f: impl FnMut(u8) -> T) -> Self {
+        Self::try_parse_with(input, Ragged::Reject, f)
+            .unwrap_or_else(|e| panic!("Unable to parse grid: {e}"))
+    }
+
+    /// Parses a block of text, converting each byte to a cell with `f`. Rows of differing length
+    /// are either rejected or padded depending on `ragged`.
+    pub fn try_parse_with(
+        input: &str,
+        ragged: Ragged,
+        mut f: impl FnMut(u8) -> T,
+    ) -> Result<Self, GridError> {
+        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+        let width = match ragged {
+            Ragged::Reject => raw.first().map_or(0, |slice| slice.len()),
+            Ragged::Pad(_) => raw.iter().map(|slice| slice.len()).max().unwrap_or(0),
+        };
+        let mut bytes = Vec::with_capacity(width * raw.len());
+
+        for (row, slice) in raw.iter().enumerate() {
+            match ragged {
+                Ragged::Reject if slice.len() != width => {
+                    return Err(GridError::RaggedRow { row, expected: width, found: slice.len() });
+                }
+                Ragged::Reject => bytes.extend(slice.iter().map(|&b| f(b))),
+                Ragged::Pad(pad) => {
+                    bytes.extend(slice.iter().map(|&b| f(b)));
+                    bytes.extend((slice.len()..width).map(|_| f(pad)));
+                }
+            }
+        }
+
+        Ok(Grid { width: width as i32, height: raw.len() as i32, bytes })
+    }
+
     #[inline]
     pub fn same_size_with<U: Copy>(&self, value: U) -> Grid<U> {
         Grid {
@@ -106,13 +245,285 @@ impl<T> Grid<T> {
             (0..self.width).map(move |x| Point::new(x, y))
         )
     }
+
+    /// Position of `point` in `bytes`, for example to use as an index into a [`BitSet`] of
+    /// visited cells.
+    ///
+    /// [`BitSet`]: crate::util::bitset::BitSet
+    #[inline]
+    pub fn index_of(&self, point: Point) -> usize {
+        (self.width * point.y + point.x) as usize
+    }
+
+    /// Inverse of [`index_of`](Grid::index_of).
+    #[inline]
+    pub fn point_of(&self, index: usize) -> Point {
+        let index = index as i32;
+        Point::new(index % self.width, index / self.width)
+    }
+
+    /// Maps any point onto the grid as if opposite edges were joined together.
+    #[inline]
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(point.x.rem_euclid(self.width), point.y.rem_euclid(self.height))
+    }
+
+    /// Bounds checked alternative to indexing, returning `None` for points outside the grid.
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.bytes[(self.width * point.y + point.x) as usize])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.bytes[(self.width * point.y + point.x) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Points one step from `point` in each of `directions` that lie inside the grid, along
+    /// with their values.
+    #[inline]
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            let next = point + direction;
+            self.get(next).map(|value| (next, value))
+        })
+    }
+
+    /// In bounds neighbours up, down, left and right, in the order of [`ORTHOGONAL`].
+    #[inline]
+    pub fn orthogonal_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// In bounds neighbours including diagonals, in the order of [`DIAGONAL`].
+    #[inline]
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(point, &DIAGONAL)
+    }
+
+    /// Breadth first search from every point in `sources` at once, stepping in each of
+    /// `directions` onto cells where `passable` holds. Returns the fewest steps from the nearest
+    /// source to every point, or `None` for points that can't be reached. Sources are always
+    /// distance zero, whether or not they are passable themselves.
+    pub fn bfs_distances(
+        &self,
+        sources: impl IntoIterator<Item = Point>,
+        directions: &[Point],
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        let mut distances = self.same_size_with(None);
+        let mut todo = VecDeque::new();
+
+        for source in sources {
+            if distances[source].is_none() {
+                distances[source] = Some(0);
+                todo.push_back(source);
+            }
+        }
+
+        while let Some(current) = todo.pop_front() {
+            let next_distance = distances[current].unwrap() + 1;
+            for (next, value) in self.neighbours(current, directions) {
+                if distances[next].is_none() && passable(value) {
+                    distances[next] = Some(next_distance);
+                    todo.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Splits the grid into regions of orthogonally adjacent cells, where neighbours belong
+    /// together when `same` holds for their values. Labels are numbered from zero in the order
+    /// that each region's top left cell appears, and index into the statistics.
+    pub fn regions(&self, mut same: impl FnMut(&T, &T) -> bool) -> Regions {
+        let mut labels = self.same_size_with(u32::MAX);
+        let mut stats = Vec::new();
+        let mut todo = Vec::new();
+
+        for start in self.points() {
+            if labels[start] != u32::MAX {
+                continue;
+            }
+
+            let label = stats.len() as u32;
+            labels[start] = label;
+            todo.push(start);
+
+            while let Some(current) = todo.pop() {
+                for (next, value) in self.orthogonal_neighbours(current) {
+                    if labels[next] == u32::MAX && same(&self[current], value) {
+                        labels[next] = label;
+                        todo.push(next);
+                    }
+                }
+            }
+
+            stats.push(RegionStats { start, area: 0, perimeter: 0, sides: 0 });
+        }
+
+        for point in labels.points() {
+            let label = labels[point];
+            let inside = |p: Point| labels.get(p) == Some(&label);
+            let region = &mut stats[label as usize];
+
+            region.area += 1;
+            region.perimeter += ORTHOGONAL.iter().filter(|&&d| !inside(point + d)).count() as u32;
+
+            // Each corner of a region's boundary starts a new side. A cell is on a convex corner
+            // when both neighbours either side of it are outside the region, or on a concave
+            // corner when both are inside but the diagonal between them is not.
+            for (first, second) in [(UP, RIGHT), (RIGHT, DOWN), (DOWN, LEFT), (LEFT, UP)] {
+                let a = inside(point + first);
+                let b = inside(point + second);
+                if (!a && !b) || (a && b && !inside(point + first + second)) {
+                    region.sides += 1;
+                }
+            }
+        }
+
+        Regions { labels, stats }
+    }
+
+    #[inline]
+    pub fn row(&self, y: i32) -> &[T] {
+        let start = (self.width * y) as usize;
+        &self.bytes[start..start + self.width as usize]
+    }
+
+    /// Rows from top to bottom, each as a slice.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Cells of column `x` from top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).map(move |y| &self[Point::new(x, y)])
+    }
+
+    /// Columns from left to right, each as an iterator over its cells from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Borrowed view of the `width` by `height` rectangle whose top left corner is `origin`.
+    /// Panics if the rectangle does not fit inside the grid.
+    pub fn window(&self, origin: Point, width: i32, height: i32) -> SubGrid<'_, T> {
+        assert!(
+            origin.x >= 0
+                && origin.y >= 0
+                && width >= 0
+                && height >= 0
+                && origin.x + width <= self.width
+                && origin.y + height <= self.height,
+            "Window {width}x{height} at {origin:?} does not fit in the {}x{} grid",
+            self.width,
+            self.height
+        );
+        SubGrid { grid: self, origin, width, height }
+    }
 }
 
+impl<T: Clone> Grid<T> {
+    /// Rotates 90 degrees clockwise, so the top row becomes the rightmost column.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| Point::new(y, self.height - 1 - x))
+    }
+
+    /// Rotates 90 degrees counter clockwise, so the top row becomes the leftmost column.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| Point::new(self.width - 1 - y, x))
+    }
+
+    /// Swaps rows and columns, reflecting along the diagonal from the top left corner.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_h(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| Point::new(self.width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_v(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| Point::new(x, self.height - 1 - y))
+    }
+
+    /// Builds a new grid where each cell `(x, y)` is copied from `source(x, y)` in this grid.
+    fn remap(&self, width: i32, height: i32, source: impl Fn(i32, i32) -> Point) -> Grid<T> {
+        let mut bytes = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                bytes.push(self[source(x, y)].clone());
+            }
+        }
+        Grid { width, height, bytes }
+    }
+}
+
+/// Connected regions found by [`Grid::regions`].
+pub struct Regions {
+    /// Label of the region that each cell belongs to.
+    pub labels: Grid<u32>,
+    /// Statistics for each region, indexed by label.
+    pub stats: Vec<RegionStats>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegionStats {
+    /// First cell of the region in reading order.
+    pub start: Point,
+    /// Number of cells.
+    pub area: u32,
+    /// Number of cell edges that border a different region or the edge of the grid.
+    pub perimeter: u32,
+    /// Number of straight sides, counted as the number of corners.
+    pub sides: u32,
+}
+
+impl Grid<Option<u32>> {
+    /// Follows a distance field from [`bfs_distances`] back downhill from `target`, returning a
+    /// shortest path that starts at a source and ends at `target`, or `None` if `target` was
+    /// not reached. `directions` should match those used for the search.
+    ///
+    /// [`bfs_distances`]: Grid::bfs_distances
+    pub fn path_to(&self, target: Point, directions: &[Point]) -> Option<Vec<Point>> {
+        let mut distance = (*self.get(target)?)?;
+        let mut path = vec![target];
+        let mut current = target;
+
+        while distance > 0 {
+            distance -= 1;
+            (current, _) = self.neighbours(current, directions).find(|&(_, &d)| d == Some(distance))?;
+            path.push(current);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+// An out of bounds x coordinate would otherwise silently wrap into the previous or next row.
 impl<T> Index<Point> for Grid<T> {
     type Output = T;
 
     #[inline]
     fn index(&self, index: Point) -> &Self::Output {
+        debug_assert!(self.contains(index), "{index:?} is outside the {}x{} grid", self.width, self.height);
         &self.bytes[(self.width * index.y + index.x) as usize]
     }
 }
@@ -120,6 +531,426 @@ impl<T> Index<Point> for Grid<T> {
 impl<T> IndexMut<Point> for Grid<T> {
     #[inline]
     fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        debug_assert!(self.contains(index), "{index:?} is outside the {}x{} grid", self.width, self.height);
         &mut self.bytes[(self.width * index.y + index.x) as usize]
     }
-}
\ No newline at end of file
+}
+
+/// A [`Grid`] whose opposite edges are joined, so it has no boundary. Indexing and neighbour
+/// iteration wrap points around the edges, allowing moves to use plain [`Point`] arithmetic.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Torus<T> {
+    pub grid: Grid<T>,
+}
+
+impl<T> Torus<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        Torus { grid }
+    }
+
+    #[inline]
+    pub fn wrap(&self, point: Point) -> Point {
+        self.grid.wrap(point)
+    }
+
+    /// Wrapped points one step from `point` in each of `directions`, along with their values.
+    /// Unlike [`Grid::neighbours`] this always yields one item per direction.
+    #[inline]
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions.iter().map(move |&direction| {
+            let next = self.wrap(point + direction);
+            (next, &self.grid[next])
+        })
+    }
+
+    #[inline]
+    pub fn orthogonal_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    #[inline]
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(point, &DIAGONAL)
+    }
+}
+
+impl<T> From<Grid<T>> for Torus<T> {
+    fn from(grid: Grid<T>) -> Self {
+        Torus { grid }
+    }
+}
+
+impl<T> Index<Point> for Torus<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.grid[self.wrap(index)]
+    }
+}
+
+impl<T> IndexMut<Point> for Torus<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        let index = self.wrap(index);
+        &mut self.grid[index]
+    }
+}
+
+/// Read only rectangular view into a [`Grid`], created by [`Grid::window`]. Points are relative
+/// to the top left corner of the window.
+#[derive(Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    pub origin: Point,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)))
+    }
+
+    #[inline]
+    pub fn row(&self, y: i32) -> &'a [T] {
+        let start = self.origin.x as usize;
+        &self.grid.row(self.origin.y + y)[start..start + self.width as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.height).map(move |y| &self.grid[self.origin + Point::new(x, y)])
+    }
+}
+
+impl<T: Clone> SubGrid<'_, T> {
+    /// Copies the window into a standalone grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let mut bytes = Vec::with_capacity((self.width * self.height) as usize);
+        self.rows().for_each(|row| bytes.extend_from_slice(row));
+        Grid { width: self.width, height: self.height, bytes }
+    }
+}
+
+impl<T> Index<Point> for SubGrid<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        debug_assert!(self.contains(index));
+        &self.grid[self.origin + index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("123\n456", |b| (b - b'0') as u16);
+
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid[Point::new(0, 0)], 1);
+        assert_eq!(grid[Point::new(2, 1)], 6);
+    }
+
+    #[test]
+    fn test_try_parse_with_rejects_ragged_rows() {
+        let result = Grid::try_parse_with("123 \n45\n678 ", Ragged::Reject, |b| b);
+
+        let error = result.err().unwrap();
+        assert_eq!(error, GridError::RaggedRow { row: 1, expected: 4, found: 2 });
+        assert_eq!(error.to_string(), "line 2 is 2 bytes wide but the first line is 4 bytes wide");
+    }
+
+    #[test]
+    fn test_try_parse_with_pads_ragged_rows() {
+        // Trailing spaces stripped from every line but the last, as an editor might.
+        let grid = Grid::try_parse_with("12\n3\n456 ", Ragged::Pad(b' '), |b| b).unwrap();
+
+        assert_eq!(grid.width, 4);
+        assert_eq!(grid.height, 3);
+        assert_eq!(grid.to_grid_string(), "12  \n3   \n456 ");
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::parse("ab\ncd");
+
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&b'd'));
+        // Would wrap onto the next row with unchecked indexing
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 1)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+
+        *grid.get_mut(ORIGIN).unwrap() = b'z';
+        assert_eq!(grid[ORIGIN], b'z');
+        assert!(grid.get_mut(Point::new(0, -1)).is_none());
+    }
+
+    #[test]
+    fn test_index_of() {
+        let grid = Grid::parse("abc\ndef");
+
+        assert_eq!(grid.index_of(Point::new(1, 1)), 4);
+        assert_eq!(grid.bytes[grid.index_of(Point::new(2, 0))], b'c');
+        for point in grid.points() {
+            assert_eq!(grid.point_of(grid.index_of(point)), point);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is outside the 2x2 grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::parse("ab\ncd");
+        let _ = grid[Point::new(2, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("abc\ndef\nghi");
+
+        let corner: Vec<_> = grid.orthogonal_neighbours(ORIGIN).collect();
+        assert_eq!(corner, vec![(Point::new(0, 1), &b'd'), (Point::new(1, 0), &b'b')]);
+
+        let centre: Vec<_> = grid.orthogonal_neighbours(Point::new(1, 1)).map(|(_, &b)| b).collect();
+        assert_eq!(centre, b"bhdf");
+
+        let all: Vec<_> = grid.all_neighbours(Point::new(2, 2)).map(|(_, &b)| b).collect();
+        assert_eq!(all, b"efh");
+        assert_eq!(grid.all_neighbours(Point::new(1, 1)).count(), 8);
+
+        let diagonals: Vec<_> = grid.neighbours(Point::new(1, 0), &JUST_DIAGONALS).collect();
+        assert_eq!(diagonals, vec![(Point::new(2, 1), &b'f'), (Point::new(0, 1), &b'd')]);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let grid = Grid::parse("abc\ndef");
+
+        assert_eq!(grid.wrap(Point::new(3, 2)), ORIGIN);
+        assert_eq!(grid.wrap(Point::new(-1, -1)), Point::new(2, 1));
+        assert_eq!(grid.wrap(Point::new(-7, 5)), Point::new(2, 1));
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let grid = Grid::parse("S..#.\n.#.#.\n.#...\n...#S");
+        let passable = |&b: &u8| b != b'#';
+
+        let single = grid.bfs_distances([ORIGIN], &ORTHOGONAL, passable);
+        assert_eq!(single[Point::new(2, 2)], Some(4));
+        assert_eq!(single[Point::new(4, 0)], Some(8));
+        assert_eq!(single[Point::new(3, 0)], None);
+
+        // Every point is measured from whichever source is closer
+        let sources: Vec<_> = grid.points().filter(|&p| grid[p] == b'S').collect();
+        let multi = grid.bfs_distances(sources, &ORTHOGONAL, passable);
+        assert_eq!(multi[Point::new(4, 0)], Some(3));
+        assert_eq!(multi[Point::new(2, 2)], Some(3));
+        assert_eq!(multi[Point::new(0, 3)], Some(3));
+
+        let diagonal = grid.bfs_distances([ORIGIN], &DIAGONAL, passable);
+        assert_eq!(diagonal[Point::new(2, 2)], Some(3));
+        assert_eq!(diagonal[Point::new(4, 0)], Some(5));
+    }
+
+    #[test]
+    fn test_bfs_source_is_always_reached() {
+        let grid = Grid::parse("#.\n##");
+        let distances = grid.bfs_distances([ORIGIN], &ORTHOGONAL, |&b| b != b'#');
+
+        assert_eq!(distances[ORIGIN], Some(0));
+        assert_eq!(distances[Point::new(1, 0)], Some(1));
+        assert_eq!(distances[Point::new(1, 1)], None);
+    }
+
+    #[test]
+    fn test_path_to() {
+        let grid = Grid::parse("S..#.\n.#.#.\n.#...\n...#E");
+        let end = grid.find(b'E').unwrap();
+        let distances = grid.bfs_distances([ORIGIN], &ORTHOGONAL, |&b| b != b'#');
+
+        let path = distances.path_to(end, &ORTHOGONAL).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], ORIGIN);
+        assert_eq!(path[7], end);
+        assert!(path.windows(2).all(|w| ORTHOGONAL.contains(&(w[1] - w[0]))));
+        assert!(path.iter().all(|&p| grid[p] != b'#'));
+
+        assert_eq!(distances.path_to(ORIGIN, &ORTHOGONAL), Some(vec![ORIGIN]));
+        assert_eq!(distances.path_to(Point::new(3, 0), &ORTHOGONAL), None);
+        assert_eq!(distances.path_to(Point::new(9, 9), &ORTHOGONAL), None);
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC");
+        let Regions { labels, stats } = grid.regions(|a, b| a == b);
+
+        assert_eq!(labels.bytes, vec![0, 0, 0, 0, 1, 1, 2, 3, 1, 1, 2, 2, 4, 4, 4, 2]);
+        let summary: Vec<_> = stats
+            .iter()
+            .map(|r| (grid[r.start], r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(b'A', 4, 10, 4), (b'B', 4, 8, 4), (b'C', 4, 10, 8), (b'D', 1, 4, 4), (b'E', 3, 8, 4)]
+        );
+    }
+
+    #[test]
+    fn test_regions_with_holes() {
+        let grid = Grid::parse("OOOOO\nOXOXO\nOOOOO");
+        let Regions { labels, stats } = grid.regions(|a, b| a == b);
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(labels[Point::new(3, 1)], 2);
+        // Inner edges around each hole count towards the outer region
+        assert_eq!((stats[0].area, stats[0].perimeter, stats[0].sides), (13, 24, 12));
+        assert_eq!((stats[1].area, stats[1].perimeter, stats[1].sides), (1, 4, 4));
+    }
+
+    #[test]
+    fn test_regions_with_predicate() {
+        // Open cells join up regardless of their value, walls only join walls
+        let grid = Grid::parse("12#\n##3\n45#");
+        let stats = grid.regions(|&a, &b| (a == b'#') == (b == b'#')).stats;
+
+        let areas: Vec<_> = stats.iter().map(|r| r.area).collect();
+        assert_eq!(areas, vec![2, 1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn test_torus_indexing() {
+        let mut torus = Torus::new(Grid::parse("abc\ndef"));
+
+        assert_eq!(torus[Point::new(-1, 0)], b'c');
+        assert_eq!(torus[Point::new(4, 3)], b'e');
+
+        torus[Point::new(-3, -2)] = b'z';
+        assert_eq!(torus.grid[ORIGIN], b'z');
+    }
+
+    #[test]
+    fn test_torus_neighbours() {
+        let torus: Torus<u8> = Grid::parse("abc\ndef\nghi").into();
+
+        let corner: Vec<_> = torus.orthogonal_neighbours(ORIGIN).collect();
+        assert_eq!(
+            corner,
+            vec![
+                (Point::new(0, 2), &b'g'),
+                (Point::new(0, 1), &b'd'),
+                (Point::new(2, 0), &b'c'),
+                (Point::new(1, 0), &b'b'),
+            ]
+        );
+
+        let all: Vec<_> = torus.all_neighbours(Point::new(2, 2)).map(|(_, &b)| b).collect();
+        assert_eq!(all, b"efdhgbca");
+    }
+
+    #[test]
+    fn test_torus_movement() {
+        // Robot from the 2024 day 14 example, moving at (2, -3) on an 11 x 7 board.
+        let torus = Torus::new(Grid::new(11, 7, 0));
+        let start = Point::new(2, 4);
+        let velocity = Point::new(2, -3);
+
+        let positions: Vec<_> = (1..=5).map(|t| torus.wrap(start + velocity * t)).collect();
+        assert_eq!(
+            positions,
+            vec![
+                Point::new(4, 1),
+                Point::new(6, 5),
+                Point::new(8, 2),
+                Point::new(10, 6),
+                Point::new(1, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotations() {
+        let grid = Grid::parse("abc\ndef");
+
+        assert_eq!(grid.rotate_cw().to_grid_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_grid_string(), "cf\nbe\nad");
+        assert!(grid.rotate_cw().rotate_ccw() == grid);
+        assert!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw() == grid);
+    }
+
+    #[test]
+    fn test_transpose_and_flips() {
+        let grid = Grid::parse("abc\ndef");
+
+        assert_eq!(grid.transpose().to_grid_string(), "ad\nbe\ncf");
+        assert_eq!(grid.flip_h().to_grid_string(), "cba\nfed");
+        assert_eq!(grid.flip_v().to_grid_string(), "def\nabc");
+        assert!(grid.flip_h().flip_v() == grid.rotate_cw().rotate_cw());
+    }
+
+    #[test]
+    fn test_transforms_on_other_types() {
+        let grid = Grid::parse_with("12\n34\n56", |b| vec![b - b'0']);
+        let rotated = grid.rotate_cw();
+
+        assert_eq!((rotated.width, rotated.height), (3, 2));
+        assert_eq!(rotated[Point::new(0, 0)], vec![5]);
+        assert_eq!(rotated[Point::new(2, 1)], vec![2]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse("abc\ndef");
+
+        let rows: Vec<_> = grid.rows().collect();
+        assert_eq!(rows, vec![b"abc", b"def"]);
+
+        let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![b"ad".to_vec(), b"be".to_vec(), b"cf".to_vec()]);
+    }
+
+    #[test]
+    fn test_window() {
+        let grid = Grid::parse("abcd\nefgh\nijkl");
+        let window = grid.window(Point::new(1, 1), 2, 2);
+
+        assert_eq!(window[ORIGIN], b'f');
+        assert_eq!(window[Point::new(1, 1)], b'k');
+        assert!(!window.contains(Point::new(2, 0)));
+        assert_eq!(window.rows().collect::<Vec<_>>(), vec![b"fg", b"jk"]);
+        assert_eq!(window.column(1).copied().collect::<Vec<_>>(), b"gk");
+        assert_eq!(window.to_grid().to_grid_string(), "fg\njk");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_window_out_of_bounds() {
+        let grid = Grid::parse("abc\ndef");
+        grid.window(Point::new(2, 0), 2, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unable to parse grid: line 2")]
+    fn test_parse_with_panics_on_ragged_rows() {
+        Grid::parse_with("###\n##", |b| b);
+    }
+}
@@ -0,0 +1,161 @@
+//! Dense 3 dimensional grid backed by a single `vec`, the counterpart of [`Grid`] for
+//! [`Point3`]. Cells are stored in layers of rows, so `z` varies slowest and `x` fastest.
+//!
+//! ```
+//!   # use aoc::util::grid3::Grid3;
+//!   # use aoc::util::point3::*;
+//!
+//!   let mut grid = Grid3::new(3, 3, 3, false);
+//!   let centre = Point3::new(1, 1, 1);
+//!   grid[centre] = true;
+//!
+//!   assert_eq!(grid.neighbours(centre, &DIAGONAL3).count(), 26);
+//!   assert_eq!(grid.neighbours(ORIGIN3, &ORTHOGONAL3).count(), 3);
+//!   assert!(grid.get(Point3::new(3, 0, 0)).is_none());
+//! ```
+//!
+//! [`Grid`]: crate::util::grid::Grid
+//! [`Point3`]: crate::util::point3::Point3
+use crate::util::point3::*;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    pub width: i32,
+    pub height: i32,
+    pub depth: i32,
+    pub bytes: Vec<T>,
+}
+
+impl<T: Copy> Grid3<T> {
+    pub fn new(width: i32, height: i32, depth: i32, value: T) -> Grid3<T> {
+        Grid3 { width, height, depth, bytes: vec![value; (width * height * depth) as usize] }
+    }
+}
+
+impl<T> Grid3<T> {
+    #[inline]
+    pub fn same_size_with<U: Copy>(&self, value: U) -> Grid3<U> {
+        Grid3::new(self.width, self.height, self.depth, value)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point3) -> bool {
+        point.x >= 0
+            && point.x < self.width
+            && point.y >= 0
+            && point.y < self.height
+            && point.z >= 0
+            && point.z < self.depth
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> + '_ {
+        (0..self.depth).flat_map(move |z| {
+            (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+
+    /// Bounds checked alternative to indexing, returning `None` for points outside the grid.
+    #[inline]
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        self.contains(point).then(|| &self.bytes[self.index_of(point)])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point3) -> Option<&mut T> {
+        if self.contains(point) {
+            let index = self.index_of(point);
+            Some(&mut self.bytes[index])
+        } else {
+            None
+        }
+    }
+
+    /// Points one step from `point` in each of `directions` that lie inside the grid, along
+    /// with their values.
+    #[inline]
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point3,
+        directions: &'a [Point3],
+    ) -> impl Iterator<Item = (Point3, &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            let next = point + direction;
+            self.get(next).map(|value| (next, value))
+        })
+    }
+
+    #[inline]
+    fn index_of(&self, point: Point3) -> usize {
+        ((point.z * self.height + point.y) * self.width + point.x) as usize
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point3) -> &Self::Output {
+        debug_assert!(
+            self.contains(index),
+            "{index:?} is outside the {}x{}x{} grid",
+            self.width,
+            self.height,
+            self.depth
+        );
+        &self.bytes[self.index_of(index)]
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point3) -> &mut Self::Output {
+        debug_assert!(
+            self.contains(index),
+            "{index:?} is outside the {}x{}x{} grid",
+            self.width,
+            self.height,
+            self.depth
+        );
+        let index = self.index_of(index);
+        &mut self.bytes[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        let mut grid = Grid3::new(2, 3, 4, 0);
+        for (i, point) in grid.points().collect::<Vec<_>>().into_iter().enumerate() {
+            grid[point] = i;
+        }
+
+        assert_eq!(grid.bytes, (0..24).collect::<Vec<_>>());
+        assert_eq!(grid[Point3::new(1, 2, 3)], 23);
+        assert_eq!(grid[Point3::new(1, 0, 1)], 7);
+        assert!(grid.points().collect::<Vec<_>>().windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid3::new(3, 3, 3, ());
+
+        assert_eq!(grid.neighbours(Point3::new(1, 1, 1), &ORTHOGONAL3).count(), 6);
+        assert_eq!(grid.neighbours(Point3::new(1, 1, 0), &ORTHOGONAL3).count(), 5);
+        assert_eq!(grid.neighbours(Point3::new(2, 2, 2), &DIAGONAL3).count(), 7);
+        assert_eq!(grid.neighbours(Point3::new(1, 0, 1), &DIAGONAL3).count(), 17);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid3::new(2, 2, 2, 0);
+        *grid.get_mut(Point3::new(1, 1, 1)).unwrap() = 5;
+
+        assert_eq!(grid.get(Point3::new(1, 1, 1)), Some(&5));
+        assert_eq!(grid.get(Point3::new(0, 0, 2)), None);
+        assert_eq!(grid.get_mut(Point3::new(-1, 0, 0)), None);
+    }
+}
@@ -2,9 +2,45 @@
 //! max heap. Splits the sorting key and value, so that you can order items without having
 //! to implement [`Ord`] on the value type.
 //!
+//! [`MinHeap`] can't change the key of an item already in the heap, so searches push the same
+//! node again whenever they find a shorter route and skip the stale entries as they are popped.
+//! Two alternatives avoid the duplicates:
+//!
+//! * [`IndexedHeap`] holds at most one entry per node, where nodes are dense `usize` ids such
+//!   as a [`Grid`] index. [`push_or_decrease`] lowers the key of a node already in the heap.
+//! * [`BucketQueue`] is a [Dial] queue for integer keys when every edge weight is at most some
+//!   small maximum. Keys popped never decrease, so each push and pop is a constant time step
+//!   through a ring of buckets.
+//!
+//! Bookkeeping for node positions isn't free, so on grids where each node has only a few
+//! neighbours [`MinHeap`] with stale entries often still beats [`IndexedHeap`]. The benchmarks in
+//! `benches/heap.rs` compare all three on the same search.
+//!
+//! ```
+//!   # use aoc::util::heap::*;
+//!
+//!   let mut heap = IndexedHeap::new(4);
+//!   heap.push(2, 10);
+//!   heap.push(3, 5);
+//!   assert!(heap.push_or_decrease(2, 1));
+//!   assert!(!heap.push_or_decrease(3, 7));
+//!   assert_eq!(heap.pop(), Some((1, 2)));
+//!   assert_eq!(heap.pop(), Some((5, 3)));
+//!
+//!   // Turning costs 1000 and moving forward costs 1
+//!   let mut queue = BucketQueue::new(1000);
+//!   queue.push(1000, "turn");
+//!   queue.push(1, "forward");
+//!   assert_eq!(queue.pop(), Some((1, "forward")));
+//!   assert_eq!(queue.pop(), Some((1000, "turn")));
+//! ```
+//!
 //! [Min heap]: https://en.wikipedia.org/wiki/Heap_(data_structure)
 //! [Dijkstra]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
 //! [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
+//! [Dial]: https://en.wikipedia.org/wiki/Bucket_queue
+//! [`Grid`]: crate::util::grid::Grid
+//! [`push_or_decrease`]: IndexedHeap::push_or_decrease
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
@@ -64,4 +100,250 @@ impl<K: Ord, V> MinHeap<K, V> {
     pub fn peek(&self) -> Option<(&K, &V)> {
         self.heap.peek().map(|w| (&w.key, &w.value))
     }
-}
\ No newline at end of file
+}
+
+/// Marks a node that isn't in an [`IndexedHeap`].
+const ABSENT: usize = usize::MAX;
+
+/// Binary min heap of node ids from 0 up to a fixed capacity, each with a key that can be
+/// lowered while the node is in the heap.
+pub struct IndexedHeap<K> {
+    /// Keys and node ids in heap order.
+    heap: Vec<(K, usize)>,
+    /// Position of each node in `heap`, or `ABSENT`.
+    positions: Vec<usize>,
+}
+
+impl<K: Ord + Copy> IndexedHeap<K> {
+    pub fn new(capacity: usize) -> Self {
+        IndexedHeap { heap: Vec::new(), positions: vec![ABSENT; capacity] }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, node: usize) -> bool {
+        self.positions[node] != ABSENT
+    }
+
+    /// Current key of a node in the heap.
+    #[inline]
+    pub fn key(&self, node: usize) -> Option<K> {
+        if self.contains(node) { Some(self.heap[self.positions[node]].0) } else { None }
+    }
+
+    /// Adds a node that isn't already in the heap. Nodes can be pushed again after being popped.
+    pub fn push(&mut self, node: usize, key: K) {
+        assert!(!self.contains(node), "Node {node} is already in the heap");
+        self.heap.push((key, node));
+        self.sift_up(self.heap.len() - 1, (key, node));
+    }
+
+    /// Lowers the key of a node already in the heap.
+    pub fn decrease_key(&mut self, node: usize, key: K) {
+        let current = self.key(node).unwrap_or_else(|| panic!("Node {node} is not in the heap"));
+        assert!(key <= current, "Key of node {node} can only decrease");
+        self.sift_up(self.positions[node], (key, node));
+    }
+
+    /// Pushes `node`, or lowers its key if it's already in the heap with a larger key. Returns
+    /// whether anything changed.
+    pub fn push_or_decrease(&mut self, node: usize, key: K) -> bool {
+        match self.key(node) {
+            None => self.push(node, key),
+            Some(current) if key < current => self.sift_up(self.positions[node], (key, node)),
+            Some(_) => return false,
+        }
+        true
+    }
+
+    #[inline]
+    pub fn peek(&self) -> Option<(K, usize)> {
+        self.heap.first().copied()
+    }
+
+    pub fn pop(&mut self) -> Option<(K, usize)> {
+        let top = *self.heap.first()?;
+        let last = self.heap.pop().unwrap();
+
+        if !self.heap.is_empty() {
+            self.sift_down(0, last);
+        }
+        self.positions[top.1] = ABSENT;
+
+        Some(top)
+    }
+
+    /// Moves `entry` from the hole at `index` towards the root until its parent is no larger.
+    fn sift_up(&mut self, mut index: usize, entry: (K, usize)) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if entry.0 >= self.heap[parent].0 {
+                break;
+            }
+            self.place(index, self.heap[parent]);
+            index = parent;
+        }
+        self.place(index, entry);
+    }
+
+    /// Moves `entry` from the hole at `index` towards the leaves until no child is smaller.
+    fn sift_down(&mut self, mut index: usize, entry: (K, usize)) {
+        let len = self.heap.len();
+        loop {
+            let mut child = 2 * index + 1;
+            if child >= len {
+                break;
+            }
+            if child + 1 < len && self.heap[child + 1].0 < self.heap[child].0 {
+                child += 1;
+            }
+            if entry.0 <= self.heap[child].0 {
+                break;
+            }
+            self.place(index, self.heap[child]);
+            index = child;
+        }
+        self.place(index, entry);
+    }
+
+    #[inline]
+    fn place(&mut self, index: usize, entry: (K, usize)) {
+        self.heap[index] = entry;
+        self.positions[entry.1] = index;
+    }
+}
+
+/// Monotone priority queue for integer keys. Every key pushed must be between the last key
+/// popped and that key plus the maximum edge weight.
+pub struct BucketQueue<V> {
+    /// Ring of buckets where the bucket for key `k` is `k % buckets.len()`.
+    buckets: Vec<Vec<V>>,
+    current: usize,
+    len: usize,
+}
+
+impl<V> BucketQueue<V> {
+    pub fn new(max_weight: usize) -> Self {
+        BucketQueue { buckets: (0..=max_weight).map(|_| Vec::new()).collect(), current: 0, len: 0 }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn push(&mut self, key: usize, value: V) {
+        debug_assert!(
+            key >= self.current && key - self.current < self.buckets.len(),
+            "Key {key} is outside the window starting at {}",
+            self.current
+        );
+        let size = self.buckets.len();
+        self.buckets[key % size].push(value);
+        self.len += 1;
+    }
+
+    /// Removes a value with the smallest key. Values with equal keys come out last in, first out.
+    pub fn pop(&mut self) -> Option<(usize, V)> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let size = self.buckets.len();
+        loop {
+            if let Some(value) = self.buckets[self.current % size].pop() {
+                self.len -= 1;
+                return Some((self.current, value));
+            }
+            self.current += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_heap() {
+        let mut heap = MinHeap::new();
+        heap.push(3, 'c');
+        heap.push(1, 'a');
+        heap.push(2, 'b');
+
+        assert_eq!(heap.peek(), Some((&1, &'a')));
+        assert_eq!(heap.pop(), Some((1, 'a')));
+        assert_eq!(heap.pop(), Some((2, 'b')));
+        assert_eq!(heap.pop(), Some((3, 'c')));
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_indexed_heap() {
+        let mut heap = IndexedHeap::new(10);
+        for (node, key) in [(0, 50), (1, 40), (2, 30), (3, 20), (4, 10), (5, 60)] {
+            heap.push(node, key);
+        }
+
+        heap.decrease_key(5, 5);
+        assert_eq!(heap.key(5), Some(5));
+        assert!(heap.push_or_decrease(9, 25));
+        assert!(heap.push_or_decrease(0, 15));
+        assert!(!heap.push_or_decrease(1, 45));
+        assert!(heap.contains(9));
+        assert!(!heap.contains(8));
+        assert_eq!(heap.len(), 7);
+
+        let order: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(order, vec![(5, 5), (10, 4), (15, 0), (20, 3), (25, 9), (30, 2), (40, 1)]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.key(5), None);
+
+        // Popped nodes can come back
+        heap.push(5, 1);
+        assert_eq!(heap.peek(), Some((1, 5)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Key of node 0 can only decrease")]
+    fn test_indexed_heap_increase() {
+        let mut heap = IndexedHeap::new(1);
+        heap.push(0, 1);
+        heap.decrease_key(0, 2);
+    }
+
+    #[test]
+    fn test_bucket_queue() {
+        let mut queue = BucketQueue::new(3);
+        queue.push(3, 'c');
+        queue.push(0, 'a');
+        queue.push(1, 'b');
+        assert_eq!(queue.len(), 3);
+
+        assert_eq!(queue.pop(), Some((0, 'a')));
+        assert_eq!(queue.pop(), Some((1, 'b')));
+        // The ring has wrapped, so key 4 shares a bucket with key 0
+        queue.push(4, 'd');
+        queue.push(2, 'e');
+        assert_eq!(queue.pop(), Some((2, 'e')));
+        assert_eq!(queue.pop(), Some((3, 'c')));
+        assert_eq!(queue.pop(), Some((4, 'd')));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+}
@@ -23,6 +23,9 @@ pub trait Integer<T>:
     const TEN: T;
 
     fn trailing_zeros(self) -> u32;
+    fn checked_add(self, rhs: T) -> Option<T>;
+    fn checked_sub(self, rhs: T) -> Option<T>;
+    fn checked_mul(self, rhs: T) -> Option<T>;
 }
 
 pub trait Unsigned<T>: Integer<T> {}
@@ -40,6 +43,21 @@ macro_rules! integer {
             fn trailing_zeros(self) -> u32 {
                 <$t>::trailing_zeros(self)
             }
+
+            #[inline]
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
         }
     )*)
 }
@@ -0,0 +1,162 @@
+//! Maps names to dense `u32` ids and back, so that graphs keyed by strings can be stored in
+//! `Vec`s indexed by id instead of hash maps.
+//!
+//! Ids are handed out in the order that names are first seen, starting from zero. Names are
+//! borrowed from the input rather than copied, so interning allocates nothing per name.
+//!
+//! Many puzzles label nodes with a fixed number of lowercase letters or digits, for example `kh`
+//! or `x00`. [`Interner::fixed_width`] looks these up in a flat table instead of hashing, treating
+//! each name as a base 36 number. Any other name still works but falls back to a hash map:
+//!
+//! ```
+//!   # use aoc::util::intern::Interner;
+//!
+//!   let mut names = Interner::fixed_width(3);
+//!   let you = names.intern("you");
+//!   let out = names.intern("out");
+//!
+//!   assert_eq!((you, out), (0, 1));
+//!   assert_eq!(names.intern("you"), you);
+//!   assert_eq!(names.get("out"), Some(out));
+//!   assert_eq!(names.get("svr"), None);
+//!   assert_eq!(names.name(out), "out");
+//! ```
+use crate::util::hash::*;
+
+/// Marks an empty slot in the fixed width table.
+const EMPTY: u32 = u32::MAX;
+
+#[derive(Clone, Default)]
+pub struct Interner<'a> {
+    names: Vec<&'a str>,
+    ids: FastMap<&'a str, u32>,
+    width: usize,
+    table: Vec<u32>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interner with a fast path for names of exactly `width` characters from `0-9` or `a-z`.
+    /// The table has `36^width` entries, so `width` can be at most 4.
+    pub fn fixed_width(width: usize) -> Self {
+        assert!((1..=4).contains(&width), "Width must be from 1 to 4, not {width}");
+        let table = vec![EMPTY; 36_usize.pow(width as u32)];
+        Interner { names: Vec::new(), ids: FastMap::new(), width, table }
+    }
+
+    /// Id of `name`, adding it if this is the first time it's been seen.
+    pub fn intern(&mut self, name: &'a str) -> u32 {
+        if let Some(id) = self.get(name) {
+            return id;
+        }
+
+        let id = self.names.len() as u32;
+        self.names.push(name);
+
+        match self.slot(name) {
+            Some(slot) => self.table[slot] = id,
+            None => {
+                self.ids.insert(name, id);
+            }
+        }
+
+        id
+    }
+
+    /// Id of `name`, or `None` if it hasn't been interned.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<u32> {
+        match self.slot(name) {
+            Some(slot) => Some(self.table[slot]).filter(|&id| id != EMPTY),
+            None => self.ids.get(name).copied(),
+        }
+    }
+
+    /// Name for an id returned by [`intern`](Interner::intern).
+    #[inline]
+    pub fn name(&self, id: u32) -> &'a str {
+        self.names[id as usize]
+    }
+
+    /// Every name, indexed by id.
+    #[inline]
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Index into the fixed width table, or `None` if the name doesn't fit.
+    #[inline]
+    fn slot(&self, name: &str) -> Option<usize> {
+        if self.width == 0 || name.len() != self.width {
+            return None;
+        }
+
+        name.bytes().try_fold(0, |slot, b| {
+            let digit = match b {
+                b'0'..=b'9' => b - b'0',
+                b'a'..=b'z' => b - b'a' + 10,
+                _ => return None,
+            };
+            Some(36 * slot + digit as usize)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hashed_names() {
+        let mut names = Interner::new();
+        assert!(names.is_empty());
+
+        assert_eq!(names.intern("London"), 0);
+        assert_eq!(names.intern("Dublin"), 1);
+        assert_eq!(names.intern("London"), 0);
+
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.get("Dublin"), Some(1));
+        assert_eq!(names.get("Belfast"), None);
+        assert_eq!(names.names(), &["London", "Dublin"]);
+    }
+
+    #[test]
+    fn test_fixed_width_names() {
+        let mut names = Interner::fixed_width(3);
+
+        assert_eq!(names.intern("x00"), 0);
+        assert_eq!(names.intern("zzz"), 1);
+        assert_eq!(names.intern("000"), 2);
+        // Names that don't fit the table share the same ids
+        assert_eq!(names.intern("Zz"), 3);
+        assert_eq!(names.intern("wire"), 4);
+        assert_eq!(names.intern("zzz"), 1);
+
+        assert_eq!(names.get("x00"), Some(0));
+        assert_eq!(names.get("wire"), Some(4));
+        assert_eq!(names.get("x01"), None);
+        assert_eq!(names.get("XYZ"), None);
+        assert_eq!(names.name(3), "Zz");
+        assert_eq!(names.len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Width must be from 1 to 4, not 5")]
+    fn test_width_too_large() {
+        Interner::fixed_width(5);
+    }
+}
@@ -0,0 +1,281 @@
+//! Sets of integers stored as sorted, disjoint ranges, for puzzles where the values involved are
+//! far too many to store one by one.
+//!
+//! Ranges can be added as either half open `start..end` or inclusive `start..=end`. Overlapping
+//! or touching ranges are merged as they are inserted, so [`contains`] is a binary search and
+//! [`total_length`] a single pass:
+//!
+//! ```
+//!   # use aoc::util::interval::RangeSet;
+//!
+//!   let mut fresh = RangeSet::new();
+//!   fresh.insert_inclusive(3..=5);
+//!   fresh.insert_inclusive(10..=14);
+//!   fresh.insert_inclusive(12..=18);
+//!   fresh.insert(5..10);
+//!   fresh.remove(7..8);
+//!
+//!   assert_eq!(fresh.ranges(), &[3..7, 8..19]);
+//!   assert!(fresh.contains(18));
+//!   assert!(!fresh.contains(7));
+//!   assert_eq!(fresh.total_length(), 15);
+//! ```
+//!
+//! [`map_ranges`] moves parts of the set to new positions in one step, as in puzzles that pass
+//! ranges of seeds through a series of lookup tables.
+//!
+//! Internally every range is half open, so an inclusive range can't end at the largest value of
+//! its type.
+//!
+//! [`contains`]: RangeSet::contains
+//! [`total_length`]: RangeSet::total_length
+//! [`map_ranges`]: RangeSet::map_ranges
+use crate::util::integer::*;
+use std::ops::{Range, RangeInclusive};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Integer<T> + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// Sorted, disjoint and non touching half open ranges.
+    #[inline]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn total_length(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |total, r| total + (r.end - r.start))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(index).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        // Every existing range that overlaps or touches the new one is merged into it.
+        let from = self.ranges.partition_point(|r| r.end < range.start);
+        let to = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if from < to {
+            range.start.min(self.ranges[from].start)..range.end.max(self.ranges[to - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(from..to, [merged]);
+    }
+
+    #[inline]
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        self.insert(start..end + T::ONE);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+
+        let from = self.ranges.partition_point(|r| r.end <= range.start);
+        let to = self.ranges.partition_point(|r| r.start < range.end);
+        if from == to {
+            return;
+        }
+
+        // Keep whatever sticks out either side of the removed range.
+        let first = self.ranges[from].start..range.start;
+        let last = range.end..self.ranges[to - 1].end;
+        let kept = [first, last].into_iter().filter(|r| r.start < r.end);
+        self.ranges.splice(from..to, kept);
+    }
+
+    #[inline]
+    pub fn remove_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        self.remove(start..end + T::ONE);
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(range.clone());
+        }
+        result
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever range finishes first can't overlap anything else.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.remove(range.clone());
+        }
+        result
+    }
+
+    /// Applies a lookup table of `(source, destination)` pairs, where every value in `source`
+    /// moves to the same offset from `destination`. Values outside every source stay where
+    /// they are. Sources should not overlap.
+    #[must_use]
+    pub fn map_ranges(&self, mappings: &[(Range<T>, T)]) -> Self {
+        let mut unmapped = self.clone();
+        let mut result = RangeSet::new();
+
+        for (source, destination) in mappings {
+            let covered = self.intersection(&RangeSet::from_iter(std::iter::once(source.clone())));
+            for range in covered.ranges {
+                let start = *destination + (range.start - source.start);
+                let end = *destination + (range.end - source.start);
+                result.insert(start..end);
+            }
+            unmapped.remove(source.clone());
+        }
+
+        result.union(&unmapped)
+    }
+}
+
+impl<T: Integer<T> + Ord> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer<T> + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Integer<T> + Ord> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert_inclusive(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = RangeSet::new();
+        set.insert(10..20);
+        set.insert(30..40);
+        set.insert(0..5);
+        assert_eq!(set.ranges(), &[0..5, 10..20, 30..40]);
+
+        // Touching ranges join up
+        set.insert(5..7);
+        assert_eq!(set.ranges(), &[0..7, 10..20, 30..40]);
+
+        // Spanning several ranges
+        set.insert(15..35);
+        assert_eq!(set.ranges(), &[0..7, 10..40]);
+
+        // Already covered, or empty
+        set.insert(11..12);
+        set.insert(50..50);
+        assert_eq!(set.ranges(), &[0..7, 10..40]);
+        assert_eq!(set.total_length(), 37);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: RangeSet<u64> = [0..=9, 20..=29].into_iter().collect();
+
+        set.remove(3..5);
+        assert_eq!(set.ranges(), &[0..3, 5..10, 20..30]);
+
+        set.remove_inclusive(8..=21);
+        assert_eq!(set.ranges(), &[0..3, 5..8, 22..30]);
+
+        set.remove(0..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: RangeSet<i32> = [-10..-5, 0..1, 7..9].into_iter().collect();
+
+        assert!(set.contains(-10));
+        assert!(!set.contains(-5));
+        assert!(set.contains(0));
+        assert!(!set.contains(1));
+        assert!(set.contains(8));
+        assert!(!set.contains(9));
+        assert!(!set.contains(-100));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: RangeSet<u32> = [0..10, 20..30].into_iter().collect();
+        let b: RangeSet<u32> = [5..25, 28..40].into_iter().collect();
+
+        assert_eq!(a.union(&b), std::iter::once(0..40).collect());
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        // Seed to soil map from 2023 day 5
+        let seeds: RangeSet<u64> = [79..93, 55..68].into_iter().collect();
+        let soil = seeds.map_ranges(&[(98..100, 50), (50..98, 52)]);
+        assert_eq!(soil.ranges(), &[57..70, 81..95]);
+
+        // Parts of a range can move in different directions, the rest stays put
+        let set: RangeSet<u64> = std::iter::once(0..10).collect();
+        let moved = set.map_ranges(&[(2..4, 100), (6..8, 0)]);
+        assert_eq!(moved.ranges(), &[0..2, 4..6, 8..10, 100..102]);
+    }
+}
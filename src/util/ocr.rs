@@ -0,0 +1,210 @@
+//! Reads the block letters that some puzzles draw as their answer.
+//!
+//! Letters are drawn with `#` for lit pixels in one of two fonts, either 6 rows high with most
+//! letters 4 pixels wide, or 10 rows high with letters 6 pixels wide. Letters are separated by at
+//! least one unlit column, except after the small `Y` which fills its whole cell. The drawing is
+//! split on empty columns and each piece is looked up in the font that matches its height:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::ocr::*;
+//!
+//!   let rows = [
+//!       "#..#.####.#....#.....##.",
+//!       "#..#.#....#....#....#..#",
+//!       "####.###..#....#....#..#",
+//!       "#..#.#....#....#....#..#",
+//!       "#..#.#....#....#....#..#",
+//!       "#..#.####.####.####..##.",
+//!   ];
+//!   let drawing = Grid::parse(&rows.join("\n"));
+//!
+//!   assert_eq!(recognise(&drawing), Some("HELLO".to_string()));
+//! ```
+//!
+//! Only the letters that have appeared in puzzles so far are known, so anything else makes the
+//! whole answer `None` rather than a string with a wrong letter in it.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+/// Reads the letters drawn with `#` in `grid`. Empty rows above and below are ignored.
+pub fn recognise(grid: &Grid<u8>) -> Option<String> {
+    let lit = |x: i32, y: i32| grid[Point::new(x, y)] == b'#';
+
+    let rows: Vec<_> = (0..grid.height).filter(|&y| (0..grid.width).any(|x| lit(x, y))).collect();
+    let (&top, &bottom) = (rows.first()?, rows.last()?);
+
+    let glyph = |columns: &[i32]| -> String {
+        let rows: Vec<String> = (top..=bottom)
+            .map(|y| columns.iter().map(|&x| if lit(x, y) { '#' } else { '.' }).collect())
+            .collect();
+        rows.join("\n")
+    };
+
+    let read = |columns: &[i32]| lookup(&glyph(columns), bottom - top + 1);
+
+    let mut letters = String::new();
+    let mut columns = Vec::new();
+
+    for x in 0..=grid.width {
+        if x < grid.width && (top..=bottom).any(|y| lit(x, y)) {
+            columns.push(x);
+        } else if !columns.is_empty() {
+            letters.push_str(&split(&columns, &read)?);
+            columns.clear();
+        }
+    }
+
+    Some(letters)
+}
+
+/// Reads a run of lit columns as one letter, or failing that as several letters touching each
+/// other. `Y` fills the whole width of its cell in the small font, so there's no empty column
+/// between it and a following letter with a lit left edge.
+fn split(columns: &[i32], read: &impl Fn(&[i32]) -> Option<char>) -> Option<String> {
+    if let Some(c) = read(columns) {
+        return Some(c.to_string());
+    }
+
+    (1..columns.len()).find_map(|width| {
+        let first = read(&columns[..width])?;
+        let rest = split(&columns[width..], read)?;
+        Some(format!("{first}{rest}"))
+    })
+}
+
+/// Reads the letters drawn by a set of lit points, which can be anywhere.
+pub fn recognise_points(points: impl IntoIterator<Item = Point>) -> Option<String> {
+    let points: Vec<_> = points.into_iter().collect();
+    let min_x = points.iter().map(|p| p.x).min()?;
+    let max_x = points.iter().map(|p| p.x).max()?;
+    let min_y = points.iter().map(|p| p.y).min()?;
+    let max_y = points.iter().map(|p| p.y).max()?;
+
+    let origin = Point::new(min_x, min_y);
+    let mut grid = Grid::new(max_x - min_x + 1, max_y - min_y + 1, b'.');
+    for point in points {
+        grid[point - origin] = b'#';
+    }
+
+    recognise(&grid)
+}
+
+/// Glyphs are stored as their rows joined by newlines.
+fn lookup(glyph: &str, height: i32) -> Option<char> {
+    let font: &[(char, &str)] = match height {
+        6 => &SMALL,
+        10 => &LARGE,
+        _ => return None,
+    };
+    font.iter().find(|&&(_, rows)| rows == glyph).map(|&(c, _)| c)
+}
+
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE: [(char, &str); 15] = [
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(font: &[(char, &str)], word: &str, gap: usize) -> Grid<u8> {
+        let glyphs: Vec<Vec<&str>> = word
+            .chars()
+            .map(|c| font.iter().find(|&&(l, _)| l == c).unwrap().1.lines().collect())
+            .collect();
+        let rows: Vec<String> = (0..glyphs[0].len())
+            .map(|y| glyphs.iter().map(|g| g[y]).collect::<Vec<_>>().join(&".".repeat(gap)))
+            .collect();
+        Grid::parse(&rows.join("\n"))
+    }
+
+    #[test]
+    fn test_every_letter() {
+        let word: String = SMALL.iter().map(|&(c, _)| c).collect();
+        assert_eq!(recognise(&draw(&SMALL, &word, 1)), Some(word));
+
+        let word: String = LARGE.iter().map(|&(c, _)| c).collect();
+        assert_eq!(recognise(&draw(&LARGE, &word, 2)), Some(word));
+    }
+
+    #[test]
+    fn test_wide_letter_touching_the_next() {
+        assert_eq!(recognise(&draw(&SMALL, "YH", 0)), Some("YH".to_string()));
+
+        // Y is the only letter without a gap after it in a real drawing
+        let drawing = [
+            "#...##..#.###.",
+            "#...##..#.#..#",
+            ".#.#.#..#.###.",
+            "..#..#..#.#..#",
+            "..#..#..#.#..#",
+            "..#...##..###.",
+        ];
+        assert_eq!(recognise(&Grid::parse(&drawing.join("\n"))), Some("YUB".to_string()));
+    }
+
+    #[test]
+    fn test_padding_and_unknown_glyphs() {
+        let padded = Grid::parse(
+            "......\n\
+             .####.\n\
+             .#....\n\
+             .###..\n\
+             .#....\n\
+             .#....\n\
+             .#....\n\
+             ......",
+        );
+        assert_eq!(recognise(&padded), Some("F".to_string()));
+
+        let unknown = Grid::parse("#.#\n.#.\n#.#\n.#.\n#.#\n.#.");
+        assert_eq!(recognise(&unknown), None);
+        assert_eq!(recognise(&Grid::new(5, 6, b'.')), None);
+    }
+
+    #[test]
+    fn test_points() {
+        let grid = draw(&SMALL, "LJ", 3);
+        let offset = Point::new(-100, 40);
+        let points = grid.points().filter(|&p| grid[p] == b'#').map(|p| p + offset);
+
+        assert_eq!(recognise_points(points), Some("LJ".to_string()));
+        assert_eq!(recognise_points([]), None);
+    }
+}
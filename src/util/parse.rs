@@ -11,14 +11,99 @@
 //! reason for the separate methods is that some Advent of Code inputs contain the `-` character
 //! as a delimiter and this would cause numbers to be incorrectly parsed as negative.
 //!
+//! The iterators favour speed and don't check for overflow. [`try_unsigned`] and [`try_signed`]
+//! parse the first number with overflow checks, returning a [`ParseError`] that points at the
+//! line and column of the problem instead of panicking:
+//!
+//! ```
+//!   # use aoc::util::parse::*;
+//!
+//!   assert_eq!("x: 255".try_unsigned::<u8>(), Ok(255));
+//!
+//!   let error = "x: -\ny: 256".try_unsigned::<u8>().unwrap_err();
+//!   assert_eq!((error.line, error.column), (2, 4));
+//!   assert_eq!(error.to_string(), "Number at line 2, column 4 does not fit in u8");
+//! ```
+//!
+//! [`iter_unsigned_with_span`] and [`iter_signed_with_span`] also yield the byte range of each
+//! number, for inputs where the position of a number matters as much as its value.
+//!
+//! Inputs made of several parts separated by blank lines can be split with [`blocks`], or with
+//! [`sections`] when the number of parts is known. Both accept `\r\n` line endings and ignore
+//! extra blank lines, including trailing ones:
+//!
+//! ```
+//!   # use aoc::util::parse::*;
+//!
+//!   let input = "1-3\r\n5-8\r\n\r\n4\r\n\r\n\r\n";
+//!
+//!   let [ranges, ingredients] = input.sections().unwrap();
+//!   assert_eq!(ranges.lines().collect::<Vec<_>>(), ["1-3", "5-8"]);
+//!   assert_eq!(ingredients, "4");
+//!
+//!   let error = input.sections::<3>().unwrap_err();
+//!   assert_eq!(error.to_string(), "Expected 3 sections separated by blank lines but found 2");
+//! ```
+//!
+//! Lines that follow a fixed sentence can be described with a [`Pattern`] instead of a regex.
+//! Each `{}` in the template captures the text up to the next piece of literal text and is
+//! converted to the matching element of the requested tuple. Text inside the braces is a label
+//! that only appears in error messages, while `{{` and `}}` match literal braces:
+//!
+//! ```
+//!   # use aoc::util::parse::*;
+//!
+//!   let pattern = Pattern::new("{name} can fly {u32} km/s for {u32} seconds");
+//!   let line = "Comet can fly 14 km/s for 10 seconds";
+//!   assert_eq!(pattern.parse(line), Ok(("Comet", 14, 10)));
+//!
+//!   let input = "Comet can fly 14 km/s for 10 seconds\nDancer can fly 16 km/s for ten seconds";
+//!   let error = pattern.parse_lines::<(&str, u32, u32)>(input).unwrap_err();
+//!   assert_eq!(
+//!       error.to_string(),
+//!       "Line 2 \"Dancer can fly 16 km/s for ten seconds\" does not match \
+//!        \"{name} can fly {u32} km/s for {u32} seconds\": {u32} can't be parsed from \"ten\""
+//!   );
+//! ```
+//!
+//! Other bases from 2 to 36 are handled by [`iter_unsigned_radix`] and [`unsigned_radix`], with
+//! letters in either case as digits above 9. Bit patterns drawn with arbitrary characters, for
+//! example `#` and `.`, are read most significant bit first by [`bits`]:
+//!
+//! ```
+//!   # use aoc::util::parse::*;
+//!
+//!   assert_eq!("10110".unsigned_radix::<u32>(2), 22);
+//!   assert_eq!("(#70c710)".unsigned_radix::<u32>(16), 0x70c710);
+//!   assert_eq!("ff,FF,1z".iter_unsigned_radix::<u16>(36).collect::<Vec<_>>(), [555, 555, 71]);
+//!   assert_eq!("#.##.".bits::<u8>(b'#'), 0b10110);
+//! ```
+//!
+//! Letters count as digits in bases above 10, so the text around hex numbers needs to avoid
+//! them.
+//!
 //! [`iter_unsigned`]: ParseOps::iter_unsigned
 //! [`iter_signed`]: ParseOps::iter_signed
+//! [`iter_unsigned_radix`]: ParseOps::iter_unsigned_radix
+//! [`unsigned_radix`]: ParseOps::unsigned_radix
+//! [`bits`]: ParseOps::bits
+//! [`blocks`]: ParseOps::blocks
+//! [`sections`]: ParseOps::sections
+//! [`try_unsigned`]: ParseOps::try_unsigned
+//! [`try_signed`]: ParseOps::try_signed
+//! [`iter_unsigned_with_span`]: ParseOps::iter_unsigned_with_span
+//! [`iter_signed_with_span`]: ParseOps::iter_signed_with_span
 use crate::util::integer::*;
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::str::Bytes;
 
 pub trait ParseByte {
     fn to_decimal(self) -> u8;
+    fn to_digit(self) -> u8;
 }
 
 impl ParseByte for u8 {
@@ -26,6 +111,17 @@ impl ParseByte for u8 {
     fn to_decimal(self) -> u8 {
         self.wrapping_sub(b'0')
     }
+
+    /// Value of `0-9`, `a-z` or `A-Z` as a digit from 0 to 35, or `u8::MAX` for any other byte.
+    #[inline]
+    fn to_digit(self) -> u8 {
+        match self {
+            b'0'..=b'9' => self - b'0',
+            b'a'..=b'z' => self - b'a' + 10,
+            b'A'..=b'Z' => self - b'A' + 10,
+            _ => u8::MAX,
+        }
+    }
 }
 
 pub struct ParseUnsigned<'a, T> {
@@ -38,22 +134,362 @@ pub struct ParseSigned<'a, T> {
     phantom: PhantomData<T>,
 }
 
+pub struct ParseUnsignedRadix<'a, T> {
+    bytes: Bytes<'a>,
+    radix: u8,
+    phantom: PhantomData<T>,
+}
+
+pub struct ParseUnsignedWithSpan<'a, T> {
+    bytes: &'a [u8],
+    index: usize,
+    phantom: PhantomData<T>,
+}
+
+pub struct ParseSignedWithSpan<'a, T> {
+    bytes: &'a [u8],
+    index: usize,
+    phantom: PhantomData<T>,
+}
+
+pub struct Blocks<'a> {
+    remaining: &'a str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input contains no digits.
+    NoNumber,
+    /// The number is too large or too small for the type.
+    Overflow(&'static str),
+}
+
+/// Why and where parsing failed. Lines and columns start at 1 and `offset` is in bytes from the
+/// start of the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, bytes: &[u8], offset: usize) -> Self {
+        let before = &bytes[..offset];
+        let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+        let column = offset - before.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1) + 1;
+        ParseError { kind, offset, line, column }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::NoNumber => {
+                write!(f, "No number found before line {}, column {}", self.line, self.column)
+            }
+            ParseErrorKind::Overflow(name) => write!(
+                f,
+                "Number at line {}, column {} does not fit in {name}",
+                self.line, self.column
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Input that didn't split into the expected number of blank line separated sections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SectionsError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for SectionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} sections separated by blank lines but found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for SectionsError {}
+
+/// Template for lines of text made of literal text and `{}` fields.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    template: String,
+    /// Literal text before, between and after the fields, so always one more than `labels`.
+    literals: Vec<String>,
+    labels: Vec<String>,
+}
+
+/// A line that doesn't match a [`Pattern`], with the 1-based line number when known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternError {
+    pub line: Option<usize>,
+    pub text: String,
+    pub template: String,
+    pub reason: String,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "Line {line} ")?;
+        }
+        write!(f, "{:?} does not match {:?}: {}", self.text, self.template, self.reason)
+    }
+}
+
+impl Error for PatternError {}
+
+/// Conversion from the text captured by a single field of a [`Pattern`].
+pub trait FromField<'a>: Sized {
+    fn from_field(field: &'a str) -> Option<Self>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    #[inline]
+    fn from_field(field: &'a str) -> Option<Self> {
+        Some(field)
+    }
+}
+
+impl FromField<'_> for String {
+    #[inline]
+    fn from_field(field: &str) -> Option<Self> {
+        Some(field.to_string())
+    }
+}
+
+impl FromField<'_> for char {
+    #[inline]
+    fn from_field(field: &str) -> Option<Self> {
+        let mut chars = field.chars();
+        chars.next().filter(|_| chars.next().is_none())
+    }
+}
+
+macro_rules! from_field {
+    ($($t:ty)*) => ($(
+        impl FromField<'_> for $t {
+            #[inline]
+            fn from_field(field: &str) -> Option<Self> {
+                field.parse().ok()
+            }
+        }
+    )*)
+}
+
+from_field!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// Conversion from every field of a [`Pattern`] at once, implemented for tuples of up to 8
+/// [`FromField`] types.
+pub trait FromFields<'a>: Sized {
+    const COUNT: usize;
+
+    /// Fails with the index of the first field that can't be converted.
+    fn from_fields(fields: &[&'a str]) -> Result<Self, usize>;
+}
+
+macro_rules! from_fields {
+    ($($t:ident $i:tt)+) => {
+        impl<'a, $($t: FromField<'a>),+> FromFields<'a> for ($($t,)+) {
+            const COUNT: usize = [$($i),+].len();
+
+            #[inline]
+            fn from_fields(fields: &[&'a str]) -> Result<Self, usize> {
+                Ok(($($t::from_field(fields[$i]).ok_or($i as usize)?,)+))
+            }
+        }
+    };
+}
+
+from_fields!(A 0);
+from_fields!(A 0 B 1);
+from_fields!(A 0 B 1 C 2);
+from_fields!(A 0 B 1 C 2 D 3);
+from_fields!(A 0 B 1 C 2 D 3 E 4);
+from_fields!(A 0 B 1 C 2 D 3 E 4 F 5);
+from_fields!(A 0 B 1 C 2 D 3 E 4 F 5 G 6);
+from_fields!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7);
+
+impl Pattern {
+    /// Panics if the template has unbalanced braces or two fields with nothing between them,
+    /// as there would be no way to tell where one ends and the next begins.
+    pub fn new(template: &str) -> Self {
+        let mut literals = vec![String::new()];
+        let mut labels = Vec::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.next_if_eq(&'{').is_some() => literals.last_mut().unwrap().push('{'),
+                '}' if chars.next_if_eq(&'}').is_some() => literals.last_mut().unwrap().push('}'),
+                '{' => {
+                    let mut label = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => label.push(c),
+                            None => panic!("Unmatched '{{' in {template:?}"),
+                        }
+                    }
+                    assert!(
+                        labels.is_empty() || !literals.last().unwrap().is_empty(),
+                        "Fields in {template:?} must be separated by literal text"
+                    );
+                    labels.push(label);
+                    literals.push(String::new());
+                }
+                '}' => panic!("Unmatched '}}' in {template:?}"),
+                _ => literals.last_mut().unwrap().push(c),
+            }
+        }
+
+        Pattern { template: template.to_string(), literals, labels }
+    }
+
+    /// Matches a single line, converting the fields to the tuple `T`.
+    pub fn parse<'a, T: FromFields<'a>>(&self, line: &'a str) -> Result<T, PatternError> {
+        self.parse_line(line, None)
+    }
+
+    /// Matches every line of `input`, stopping at the first one that doesn't fit.
+    pub fn parse_lines<'a, T: FromFields<'a>>(
+        &self,
+        input: &'a str,
+    ) -> Result<Vec<T>, PatternError> {
+        input.lines().enumerate().map(|(i, line)| self.parse_line(line, Some(i + 1))).collect()
+    }
+
+    fn parse_line<'a, T: FromFields<'a>>(
+        &self,
+        line: &'a str,
+        number: Option<usize>,
+    ) -> Result<T, PatternError> {
+        assert_eq!(
+            T::COUNT,
+            self.labels.len(),
+            "{:?} has {} fields but {} were requested",
+            self.template,
+            self.labels.len(),
+            T::COUNT
+        );
+
+        let error = |reason| PatternError {
+            line: number,
+            text: line.to_string(),
+            template: self.template.clone(),
+            reason,
+        };
+        let fields = self.split(line).map_err(error)?;
+        T::from_fields(&fields)
+            .map_err(|i| error(format!("{} can't be parsed from {:?}", self.label(i), fields[i])))
+    }
+
+    /// Each field takes the shortest text that is followed by the next literal, except the last
+    /// which runs up to the literal that ends the line.
+    fn split<'a>(&self, line: &'a str) -> Result<Vec<&'a str>, String> {
+        let first = &self.literals[0];
+        let mut rest = line
+            .strip_prefix(first.as_str())
+            .ok_or_else(|| format!("expected it to start with {first:?}"))?;
+        let mut fields = Vec::with_capacity(self.labels.len());
+
+        for (i, literal) in self.literals.iter().enumerate().skip(1) {
+            let end = if i + 1 == self.literals.len() {
+                rest.strip_suffix(literal.as_str()).map(str::len)
+            } else {
+                rest.find(literal.as_str())
+            };
+            let end =
+                end.ok_or_else(|| format!("expected {literal:?} after {}", self.label(i - 1)))?;
+
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+
+        if rest.is_empty() { Ok(fields) } else { Err(format!("unexpected {rest:?} at the end")) }
+    }
+
+    fn label(&self, index: usize) -> String {
+        match self.labels[index].as_str() {
+            "" => format!("field {}", index + 1),
+            label => format!("{{{label}}}"),
+        }
+    }
+}
+
 pub trait ParseOps {
     fn unsigned<T: Unsigned<T>>(&self) -> T;
     fn signed<T: Signed<T>>(&self) -> T;
+    fn try_unsigned<T: Unsigned<T>>(&self) -> Result<T, ParseError>;
+    fn try_signed<T: Signed<T>>(&self) -> Result<T, ParseError>;
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T>;
     fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T>;
+    fn unsigned_radix<T: Unsigned<T>>(&self, radix: u32) -> T;
+    fn iter_unsigned_radix<T: Unsigned<T>>(&self, radix: u32) -> ParseUnsignedRadix<'_, T>;
+    fn bits<T: Unsigned<T>>(&self, one: u8) -> T;
+    fn iter_unsigned_with_span<T: Unsigned<T>>(&self) -> ParseUnsignedWithSpan<'_, T>;
+    fn iter_signed_with_span<T: Signed<T>>(&self) -> ParseSignedWithSpan<'_, T>;
+    fn blocks(&self) -> Blocks<'_>;
+    fn sections<const N: usize>(&self) -> Result<[&str; N], SectionsError>;
 }
 
 impl<S: AsRef<str>> ParseOps for S {
     fn unsigned<T: Unsigned<T>>(&self) -> T {
         let str = self.as_ref();
-        try_unsigned(&mut str.bytes()).unwrap_or_else(|| panic!("Unable to parse \"{str}\""))
+        str.try_unsigned().unwrap_or_else(|e| panic!("Unable to parse \"{str}\": {e}"))
     }
 
     fn signed<T: Signed<T>>(&self) -> T {
         let str = self.as_ref();
-        try_signed(&mut str.bytes()).unwrap_or_else(|| panic!("Unable to parse \"{str}\""))
+        str.try_signed().unwrap_or_else(|e| panic!("Unable to parse \"{str}\": {e}"))
+    }
+
+    fn try_unsigned<T: Unsigned<T>>(&self) -> Result<T, ParseError> {
+        let bytes = self.as_ref().as_bytes();
+        let span = unsigned_span(bytes, 0)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::NoNumber, bytes, bytes.len()))?;
+        checked_value(&bytes[span.clone()], false).ok_or_else(|| {
+            ParseError::new(ParseErrorKind::Overflow(type_name::<T>()), bytes, span.start)
+        })
+    }
+
+    fn try_signed<T: Signed<T>>(&self) -> Result<T, ParseError> {
+        let bytes = self.as_ref().as_bytes();
+        let span = signed_span(bytes, 0)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::NoNumber, bytes, bytes.len()))?;
+        let (digits, negative) = match bytes[span.start] {
+            b'-' => (&bytes[span.start + 1..span.end], true),
+            _ => (&bytes[span.clone()], false),
+        };
+        checked_value(digits, negative).ok_or_else(|| {
+            ParseError::new(ParseErrorKind::Overflow(type_name::<T>()), bytes, span.start)
+        })
+    }
+
+    fn iter_unsigned_with_span<T: Unsigned<T>>(&self) -> ParseUnsignedWithSpan<'_, T> {
+        ParseUnsignedWithSpan { bytes: self.as_ref().as_bytes(), index: 0, phantom: PhantomData }
+    }
+
+    fn iter_signed_with_span<T: Signed<T>>(&self) -> ParseSignedWithSpan<'_, T> {
+        ParseSignedWithSpan { bytes: self.as_ref().as_bytes(), index: 0, phantom: PhantomData }
+    }
+
+    fn blocks(&self) -> Blocks<'_> {
+        Blocks { remaining: self.as_ref() }
+    }
+
+    fn sections<const N: usize>(&self) -> Result<[&str; N], SectionsError> {
+        let sections: Vec<_> = self.blocks().collect();
+        sections.try_into().map_err(|s: Vec<_>| SectionsError { expected: N, found: s.len() })
     }
 
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T> {
@@ -63,6 +499,52 @@ impl<S: AsRef<str>> ParseOps for S {
     fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T> {
         ParseSigned { bytes: self.as_ref().bytes(), phantom: PhantomData }
     }
+
+    fn unsigned_radix<T: Unsigned<T>>(&self, radix: u32) -> T {
+        assert!((2..=36).contains(&radix), "Radix must be from 2 to 36, not {radix}");
+        let str = self.as_ref();
+        let bytes = str.as_bytes();
+        let radix = radix as u8;
+
+        let start = bytes
+            .iter()
+            .position(|b| b.to_digit() < radix)
+            .unwrap_or_else(|| panic!("Unable to parse \"{str}\" in base {radix}"));
+        let base = T::from(radix);
+
+        bytes[start..]
+            .iter()
+            .take_while(|b| b.to_digit() < radix)
+            .try_fold(T::ZERO, |n, b| n.checked_mul(base)?.checked_add(T::from(b.to_digit())))
+            .unwrap_or_else(|| {
+                let e = ParseError::new(ParseErrorKind::Overflow(type_name::<T>()), bytes, start);
+                panic!("Unable to parse \"{str}\": {e}")
+            })
+    }
+
+    fn iter_unsigned_radix<T: Unsigned<T>>(&self, radix: u32) -> ParseUnsignedRadix<'_, T> {
+        assert!((2..=36).contains(&radix), "Radix must be from 2 to 36, not {radix}");
+        ParseUnsignedRadix {
+            bytes: self.as_ref().bytes(),
+            radix: radix as u8,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Reads every byte as a bit, 1 if it equals `one` and 0 otherwise, skipping whitespace.
+    /// Panics if there are more bits than fit in `T`.
+    fn bits<T: Unsigned<T>>(&self, one: u8) -> T {
+        let str = self.as_ref();
+        let bits = str.bytes().filter(|b| !b.is_ascii_whitespace());
+        let width = 8 * size_of::<T>();
+
+        assert!(
+            bits.clone().count() <= width,
+            "Unable to read \"{str}\" as bits: more than {width} do not fit in {}",
+            type_name::<T>()
+        );
+        bits.fold(T::ZERO, |n, b| (n << 1) | T::from((b == one) as u8))
+    }
 }
 
 impl<T: Unsigned<T>> Iterator for ParseUnsigned<'_, T> {
@@ -76,7 +558,7 @@ impl<T: Unsigned<T>> Iterator for ParseUnsigned<'_, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        try_unsigned(&mut self.bytes)
+        next_unsigned(&mut self.bytes)
     }
 }
 
@@ -91,11 +573,46 @@ impl<T: Signed<T>> Iterator for ParseSigned<'_, T> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        try_signed(&mut self.bytes)
+        next_signed(&mut self.bytes)
     }
 }
 
-fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
+impl<T: Unsigned<T>> Iterator for ParseUnsignedRadix<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        next_unsigned_radix(&mut self.bytes, self.radix)
+    }
+}
+
+impl<T: Unsigned<T>> Iterator for ParseUnsignedWithSpan<'_, T> {
+    /// Each number along with its byte range in the input.
+    type Item = (T, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = unsigned_span(self.bytes, self.index)?;
+        self.index = span.end;
+        let n = next_unsigned(&mut self.bytes[span.clone()].iter().copied())?;
+        Some((n, span))
+    }
+}
+
+impl<T: Signed<T>> Iterator for ParseSignedWithSpan<'_, T> {
+    /// Each number along with its byte range in the input, including any minus sign.
+    type Item = (T, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = signed_span(self.bytes, self.index)?;
+        self.index = span.end;
+        let n = next_signed(&mut self.bytes[span.clone()].iter().copied())?;
+        Some((n, span))
+    }
+}
+
+fn next_unsigned<T: Unsigned<T>>(bytes: &mut impl Iterator<Item = u8>) -> Option<T> {
     let mut n = loop {
         let digit = bytes.next()?.to_decimal();
         if digit < 10 {
@@ -114,15 +631,37 @@ fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     Some(n)
 }
 
-fn try_signed<T: Signed<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
-    let (mut n, negative) = loop {
-        let digit = bytes.next()?.to_decimal();
-        if digit == 253 {
-            break (T::ZERO, true);
+fn next_unsigned_radix<T: Unsigned<T>>(
+    bytes: &mut impl Iterator<Item = u8>,
+    radix: u8,
+) -> Option<T> {
+    let mut n = loop {
+        let digit = bytes.next()?.to_digit();
+        if digit < radix {
+            break T::from(digit);
+        }
+    };
+
+    let base = T::from(radix);
+    for byte in bytes {
+        let digit = byte.to_digit();
+        if digit >= radix {
+            break;
         }
+        n = base * n + T::from(digit);
+    }
+
+    Some(n)
+}
+
+fn next_signed<T: Signed<T>>(bytes: &mut impl Iterator<Item = u8>) -> Option<T> {
+    let mut negative = false;
+    let mut n = loop {
+        let digit = bytes.next()?.to_decimal();
         if digit < 10 {
-            break (T::from(digit), false);
+            break T::from(digit);
         }
+        negative = digit == 253;
     };
 
     for byte in bytes {
@@ -134,4 +673,255 @@ fn try_signed<T: Signed<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
     }
 
     Some(if negative { -n } else { n })
-}
\ No newline at end of file
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    /// Lines between blank lines, without the line ending after the last line.
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.remaining;
+        let mut start = None;
+        let mut end = 0;
+        let mut position = 0;
+
+        for line in rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+
+            if !content.trim().is_empty() {
+                start.get_or_insert(position);
+                end = position + content.len();
+            } else if start.is_some() {
+                break;
+            }
+            position += line.len();
+        }
+
+        self.remaining = &rest[position..];
+        start.map(|start| &rest[start..end])
+    }
+}
+
+/// Byte range of the next run of digits at or after `from`.
+fn unsigned_span(bytes: &[u8], from: usize) -> Option<Range<usize>> {
+    let start = from + bytes[from..].iter().position(|b| b.to_decimal() < 10)?;
+    Some(start..digits_end(bytes, start))
+}
+
+/// Byte range of the next run of digits at or after `from`, including a leading minus sign.
+/// A minus sign only counts when a digit follows it directly.
+fn signed_span(bytes: &[u8], from: usize) -> Option<Range<usize>> {
+    let digits = unsigned_span(bytes, from)?;
+    let negative = digits.start > from && bytes[digits.start - 1] == b'-';
+    Some(digits.start - negative as usize..digits.end)
+}
+
+fn digits_end(bytes: &[u8], from: usize) -> usize {
+    bytes[from..].iter().position(|b| b.to_decimal() >= 10).map_or(bytes.len(), |i| from + i)
+}
+
+/// Value of a run of digits, or `None` if it overflows. Negative numbers are built up below
+/// zero so that the most negative value of a type can be parsed.
+fn checked_value<T: Integer<T>>(digits: &[u8], negative: bool) -> Option<T> {
+    digits.iter().try_fold(T::ZERO, |n, b| {
+        let digit = T::from(b.to_decimal());
+        let n = n.checked_mul(T::TEN)?;
+        if negative { n.checked_sub(digit) } else { n.checked_add(digit) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_unsigned() {
+        assert_eq!("abc 123 def".try_unsigned::<u32>(), Ok(123));
+        assert_eq!("-7".try_unsigned::<u32>(), Ok(7));
+        assert_eq!("255".try_unsigned::<u8>(), Ok(255));
+        assert_eq!("18446744073709551615".try_unsigned::<u64>(), Ok(u64::MAX));
+
+        assert_eq!("12\nab 256".try_unsigned::<u8>(), Ok(12));
+
+        let error = "ab\ncd 256".try_unsigned::<u8>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Overflow("u8"));
+        assert_eq!((error.offset, error.line, error.column), (6, 2, 4));
+
+        let error = "18446744073709551616".try_unsigned::<u64>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Overflow("u64"));
+
+        let error = "no\nnumbers".try_unsigned::<u32>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::NoNumber);
+        assert_eq!((error.offset, error.line, error.column), (10, 2, 8));
+        assert_eq!(error.to_string(), "No number found before line 2, column 8");
+    }
+
+    #[test]
+    fn test_try_signed() {
+        assert_eq!("x=-42".try_signed::<i32>(), Ok(-42));
+        assert_eq!("-32768".try_signed::<i16>(), Ok(i16::MIN));
+        assert_eq!("32767".try_signed::<i16>(), Ok(i16::MAX));
+
+        let error = "  32768".try_signed::<i16>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Overflow("i16"));
+        assert_eq!(error.column, 3);
+
+        let error = " -32769".try_signed::<i16>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Overflow("i16"));
+        assert_eq!(error.column, 2);
+
+        // A minus sign on its own is not a number
+        assert_eq!("-".try_signed::<i32>().unwrap_err().kind, ParseErrorKind::NoNumber);
+        assert_eq!("a - b -7".try_signed::<i32>(), Ok(-7));
+    }
+
+    #[test]
+    fn test_iter_signed_skips_lone_minus() {
+        let values: Vec<i32> = "a - b 3 --4 -x5".iter_signed().collect();
+        assert_eq!(values, vec![3, -4, 5]);
+
+        let spans: Vec<(i32, _)> = "a - b 3 --4".iter_signed_with_span().collect();
+        assert_eq!(spans, vec![(3, 6..7), (-4, 9..11)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Unable to parse \"99999\": Number at line 1, column 1 does not fit in u16"
+    )]
+    fn test_unsigned_panics_with_context() {
+        "99999".unsigned::<u16>();
+    }
+
+    #[test]
+    fn test_radix() {
+        assert_eq!("0".unsigned_radix::<u8>(2), 0);
+        assert_eq!("x=11111111".unsigned_radix::<u8>(2), 255);
+        assert_eq!("2101".unsigned_radix::<u32>(2), 5);
+        assert_eq!("777".unsigned_radix::<u32>(8), 511);
+        assert_eq!("#DeadBeef".unsigned_radix::<u64>(16), 0xdeadbeef);
+        assert_eq!("zz".unsigned_radix::<u32>(36), 1295);
+
+        let colours: Vec<u32> = "(#70c710) (#0dc571)".iter_unsigned_radix(16).collect();
+        assert_eq!(colours, vec![0x70c710, 0x0dc571]);
+
+        let binary: Vec<u8> = "00100\n11110\n10110".iter_unsigned_radix(2).collect();
+        assert_eq!(binary, vec![4, 30, 22]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Radix must be from 2 to 36, not 37")]
+    fn test_radix_out_of_range() {
+        "1".unsigned_radix::<u32>(37);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Unable to parse \"#1ff\": Number at line 1, column 2 does not fit in u8"
+    )]
+    fn test_radix_overflow() {
+        "#1ff".unsigned_radix::<u8>(16);
+    }
+
+    #[test]
+    fn test_bits() {
+        assert_eq!("10110".bits::<u32>(b'1'), 22);
+        assert_eq!("#.##.\n".bits::<u8>(b'#'), 22);
+        assert_eq!("".bits::<u64>(b'1'), 0);
+        assert_eq!("1".repeat(64).bits::<u64>(b'1'), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "more than 8 do not fit in u8")]
+    fn test_bits_overflow() {
+        "#.#.#.#.\n#".bits::<u8>(b'#');
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks: Vec<_> = "\n\na\nb\n\n  \nc\n\n\n".blocks().collect();
+        assert_eq!(blocks, vec!["a\nb", "c"]);
+
+        let blocks: Vec<_> = "#.\r\n.#\r\n\r\n..\r\n##".blocks().collect();
+        assert_eq!(blocks, vec!["#.\r\n.#", "..\r\n##"]);
+
+        assert_eq!("".blocks().count(), 0);
+        assert_eq!("\n\r\n".blocks().count(), 0);
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!("a\n\nb\n".sections(), Ok(["a", "b"]));
+        assert_eq!("a\n".sections(), Ok(["a"]));
+        assert_eq!("a\n".sections::<2>(), Err(SectionsError { expected: 2, found: 1 }));
+        assert_eq!("a\n\nb\n\nc".sections::<2>(), Err(SectionsError { expected: 2, found: 3 }));
+    }
+
+    #[test]
+    fn test_pattern() {
+        let gate = Pattern::new("{} {op} {} -> {}");
+        assert_eq!(gate.parse("x00 AND y00 -> z00"), Ok(("x00", "AND", "y00", "z00")));
+        assert_eq!(gate.parse("a OR b -> c"), Ok(("a", "OR", "b", "c".to_string())));
+
+        // Escaped braces, and the last field taking everything up to the final literal
+        let machine = Pattern::new("[{}] {} {{{}}}");
+        let (lights, buttons, joltages): (&str, &str, &str) =
+            machine.parse("[.##.] (3) (1,3) {3,5}").unwrap();
+        assert_eq!((lights, buttons, joltages), (".##.", "(3) (1,3)", "3,5"));
+
+        let shift = Pattern::new("{} LSHIFT {} -> {}");
+        assert_eq!(shift.parse("x LSHIFT 2 -> f"), Ok(("x", 2_u8, 'f')));
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        let pattern = Pattern::new("Sue {u32}: {}");
+        let error = |line| pattern.parse::<(u32, &str)>(line).unwrap_err().reason;
+
+        assert_eq!(error("Bob 1: cats"), "expected it to start with \"Sue \"");
+        assert_eq!(error("Sue 1 cats"), "expected \": \" after {u32}");
+        assert_eq!(error("Sue x: cats"), "{u32} can't be parsed from \"x\"");
+
+        let error = Pattern::new("{}-{}").parse_lines::<(u8, u8)>("1-2\n3-4\n5-x").unwrap_err();
+        assert_eq!(error.line, Some(3));
+        assert_eq!(
+            error.to_string(),
+            "Line 3 \"5-x\" does not match \"{}-{}\": field 2 can't be parsed from \"x\""
+        );
+
+        let error = Pattern::new("a{}b").parse::<(char,)>("axbc").unwrap_err();
+        assert_eq!(error.reason, "expected \"b\" after field 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "must be separated by literal text")]
+    fn test_pattern_adjacent_fields() {
+        Pattern::new("{}{}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unmatched '{' in \"{name can fly\"")]
+    fn test_pattern_unclosed_brace() {
+        Pattern::new("{name can fly");
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 fields but 1 were requested")]
+    fn test_pattern_wrong_tuple() {
+        let _ = Pattern::new("{}-{}").parse::<(u8,)>("1-2");
+    }
+
+    #[test]
+    fn test_iter_with_span() {
+        let schematic = "467..114\n...*..-5\n.35.....";
+
+        let unsigned: Vec<(u32, _)> = schematic.iter_unsigned_with_span().collect();
+        assert_eq!(unsigned, vec![(467, 0..3), (114, 5..8), (5, 16..17), (35, 19..21)]);
+
+        let signed: Vec<(i32, _)> = schematic.iter_signed_with_span().collect();
+        assert_eq!(signed, vec![(467, 0..3), (114, 5..8), (-5, 15..17), (35, 19..21)]);
+
+        // Spans are consistent with the plain iterators
+        let values: Vec<u32> = schematic.iter_unsigned().collect();
+        assert_eq!(values, unsigned.iter().map(|&(n, _)| n).collect::<Vec<_>>());
+    }
+}
@@ -21,6 +21,9 @@
 //! and a [`manhattan`] function for the
 //! [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between 2 points.
 //!
+//! [`Point64`] has the same API with `i64` coordinates, for puzzles where coordinates or the
+//! products of coordinates would overflow an `i32`.
+//!
 //! [`clockwise`]: Point::clockwise
 //! [`counter_clockwise`]: Point::counter_clockwise
 //! [`manhattan`]: Point::manhattan
@@ -183,6 +186,115 @@ impl Ord for Point {
     }
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Point64 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point64 {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point64 { x, y }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clockwise(self) -> Self {
+        Point64::new(-self.y, self.x)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn counter_clockwise(self) -> Self {
+        Point64::new(self.y, -self.x)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn signum(self, other: Self) -> Self {
+        Point64::new((self.x - other.x).signum(), (self.y - other.y).signum())
+    }
+}
+
+impl From<Point> for Point64 {
+    #[inline]
+    fn from(point: Point) -> Self {
+        Point64::new(point.x as i64, point.y as i64)
+    }
+}
+
+impl Hash for Point64 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.x as u64);
+        state.write_u64(self.y as u64);
+    }
+}
+
+impl Add for Point64 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point64::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point64 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Mul<i64> for Point64 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self {
+        Point64::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Sub for Point64 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point64::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point64 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl PartialOrd for Point64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Point64 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Same row-major order as Point
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +315,27 @@ mod tests {
         assert!(adjacent.contains(&Point::new( 1,  1)));
     }
 
+    #[test]
+    fn test_point64() {
+        let a = Point64::new(3_000_000_000, -2);
+        let b = Point64::from(Point::new(-5, 7));
+
+        assert_eq!(a + b, Point64::new(2_999_999_995, 5));
+        assert_eq!(a - b, Point64::new(3_000_000_005, -9));
+        assert_eq!(a * 2, Point64::new(6_000_000_000, -4));
+        assert_eq!(a.manhattan(b), 3_000_000_014);
+        assert_eq!(a.signum(b), Point64::new(1, -1));
+
+        // Rotations match Point
+        let up = Point64::from(UP);
+        assert_eq!(up.clockwise(), Point64::from(RIGHT));
+        assert_eq!(up.counter_clockwise(), Point64::from(LEFT));
+
+        let mut points = vec![a, b, Point64::new(0, -2)];
+        points.sort();
+        assert_eq!(points, vec![Point64::new(0, -2), a, b]);
+    }
+
     #[test]
     fn test_point_ordering() {
         // Test row-major ordering (y first, then x)
@@ -0,0 +1,206 @@
+//! 3 dimensional counterpart to [`Point`], designed to work together with [`Grid3`].
+//!
+//! The same [`std::ops`] traits are implemented so that expressions read the same as in 2D:
+//!
+//! ```
+//!   # use aoc::util::point3::*;
+//!
+//!   let a = Point3::new(1, 2, 3);
+//!   let b = Point3::new(4, 6, 3);
+//!
+//!   assert_eq!(a + b, Point3::new(5, 8, 6));
+//!   assert_eq!(b - a, Point3::new(3, 4, 0));
+//!   assert_eq!(a * 2, Point3::new(2, 4, 6));
+//!   assert_eq!(a.manhattan(b), 7);
+//!   assert_eq!(a.euclidean_squared(b), 25);
+//! ```
+//!
+//! [`ORTHOGONAL3`] holds the 6 neighbours that share a face and [`DIAGONAL3`] all 26 neighbours
+//! that share a face, edge or corner.
+//!
+//! [`Point`]: crate::util::point::Point
+//! [`Grid3`]: crate::util::grid3::Grid3
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+pub const ORIGIN3: Point3 = Point3::new(0, 0, 0);
+pub const ORTHOGONAL3: [Point3; 6] = [
+    Point3::new(0, -1, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(-1, 0, 0),
+    Point3::new(1, 0, 0),
+    Point3::new(0, 0, -1),
+    Point3::new(0, 0, 1),
+];
+// Left to right, top to bottom, then front to back.
+pub const DIAGONAL3: [Point3; 26] = {
+    let mut result = [ORIGIN3; 26];
+    let mut index = 0;
+    let mut n = 0;
+
+    while n < 27 {
+        // Skip the centre
+        if n != 13 {
+            result[index] = Point3::new(n % 3 - 1, n / 3 % 3 - 1, n / 9 - 1);
+            index += 1;
+        }
+        n += 1;
+    }
+
+    result
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Square of the straight line distance. Coordinates are widened to `i64` before subtracting,
+    /// so the result is exact whenever it fits in an `i64`, which is always true for coordinates
+    /// within ±2^29.
+    #[inline]
+    #[must_use]
+    pub fn euclidean_squared(self, other: Self) -> i64 {
+        let dx = self.x as i64 - other.x as i64;
+        let dy = self.y as i64 - other.y as i64;
+        let dz = self.z as i64 - other.z as i64;
+        dx * dx + dy * dy + dz * dz
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn signum(self, other: Self) -> Self {
+        Point3::new(
+            (self.x - other.x).signum(),
+            (self.y - other.y).signum(),
+            (self.z - other.z).signum(),
+        )
+    }
+}
+
+impl From<[i32; 3]> for Point3 {
+    #[inline]
+    fn from([x, y, z]: [i32; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Hash for Point3 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(self.x as u32);
+        state.write_u32(self.y as u32);
+        state.write_u32(self.z as u32);
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl PartialOrd for Point3 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Point3 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Compare z first, then y, then x, matching the layout of Grid3
+        (self.z, self.y, self.x).cmp(&(other.z, other.y, other.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbour_constants() {
+        assert_eq!(DIAGONAL3.len(), 26);
+        assert!(!DIAGONAL3.contains(&ORIGIN3));
+        assert!(ORTHOGONAL3.iter().all(|p| DIAGONAL3.contains(p)));
+        assert!(ORTHOGONAL3.iter().all(|p| p.manhattan(ORIGIN3) == 1));
+
+        // Sorted in the same order as the points of a grid
+        assert!(DIAGONAL3.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(DIAGONAL3[0], Point3::new(-1, -1, -1));
+        assert_eq!(DIAGONAL3[25], Point3::new(1, 1, 1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(-50_000, 0, 70_000);
+        let b = Point3::new(50_000, 3, 66_000);
+
+        assert_eq!(a.manhattan(b), 104_003);
+        assert_eq!(a.euclidean_squared(b), 10_016_000_009);
+        assert_eq!(a.signum(b), Point3::new(-1, -1, 1));
+
+        // Outside ±2^29, but the difference of 3 * 10^9 squared still fits in an i64
+        let far = Point3::new(1_500_000_000, 0, 0);
+        let opposite = Point3::new(-1_500_000_000, 0, 0);
+        assert_eq!(far.euclidean_squared(opposite), 9_000_000_000_000_000_000);
+
+        // Opposite corners of the ±2^29 cube are the furthest apart that is always safe
+        let corner = Point3::new(1 << 29, 1 << 29, 1 << 29);
+        let other = Point3::new(-(1 << 29), -(1 << 29), -(1 << 29));
+        assert_eq!(corner.euclidean_squared(other), 3 << 60);
+    }
+}
@@ -0,0 +1,517 @@
+//! Graph searches for puzzles where the graph is only known through a function that lists the
+//! neighbours of a node, for example a reindeer's position and facing in a maze, or the state of
+//! a set of lights after pressing each button.
+//!
+//! Every search takes the start node, a `successors` closure and a `success` closure that marks
+//! the goal. Weighted searches expect successors as `(node, weight)` pairs and return the path
+//! along with its total cost:
+//!
+//! ```
+//!   # use aoc::util::search::*;
+//!
+//!   // Reach 10 from 1 by adding 1 (costs 1) or doubling (costs 3)
+//!   let successors = |&n: &u32| [(n + 1, 1), (n * 2, 3)].into_iter().filter(|&(n, _)| n <= 10);
+//!
+//!   let (path, cost) = dijkstra(1, successors, |&n| n == 10).unwrap();
+//!   assert_eq!((path, cost), (vec![1, 2, 3, 4, 5, 10], 7));
+//!
+//!   let path = bfs(1, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+//!   assert_eq!(path, vec![1, 2, 4, 5, 10]);
+//! ```
+//!
+//! Nodes are stored once in a `Vec` and looked up through a [`FastMap`], so they only need to be
+//! [`Clone`], [`Eq`] and [`Hash`]. [`astar_all`] keeps every parent on a shortest route instead
+//! of only the first, returning [`ShortestPaths`] to find every node on any best path.
+//!
+//! [`count_paths`] counts the routes to the goal rather than finding one, for graphs without
+//! cycles such as the ways to build a string from pieces.
+//!
+//! Each search has a `_with` variant that reports progress to a [`Visitor`], so that a
+//! visualisation can draw the frontier as it grows. Pass `&mut ()` to ignore progress.
+//!
+//! [`FastMap`]: crate::util::hash::FastMap
+use crate::util::bitset::BitSet;
+use crate::util::hash::*;
+use crate::util::heap::MinHeap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Marks the start node, which has no parent.
+const NONE: usize = usize::MAX;
+
+/// Hooks called as a search runs. Costs are the number of steps for [`bfs`].
+pub trait Visitor<N, C> {
+    /// A node joins the frontier, or rejoins it with a lower cost.
+    fn discover(&mut self, _node: &N, _cost: C) {}
+
+    /// A node leaves the frontier and its successors are about to be listed.
+    fn expand(&mut self, _node: &N, _cost: C) {}
+}
+
+impl<N, C> Visitor<N, C> for () {}
+
+/// Shortest path by number of steps, including both the start and the goal.
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_with(start, successors, success, &mut ())
+}
+
+pub fn bfs_with<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+    visitor: &mut impl Visitor<N, usize>,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new(start, 0);
+    let mut todo = VecDeque::from([0]);
+    visitor.discover(&explored.nodes[0], 0);
+
+    while let Some(index) = todo.pop_front() {
+        let steps = explored.costs[index];
+        visitor.expand(&explored.nodes[index], steps);
+
+        if success(&explored.nodes[index]) {
+            return Some(explored.path(index));
+        }
+
+        for next in successors(&explored.nodes[index]) {
+            if !explored.indices.contains_key(&next) {
+                let next = explored.add(next, steps + 1, index);
+                visitor.discover(&explored.nodes[next], steps + 1);
+                todo.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Lowest cost path, including both the start and the goal. Weights must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_with(start, successors, |_| C::default(), success, &mut ())
+}
+
+pub fn dijkstra_with<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+    visitor: &mut impl Visitor<N, C>,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_with(start, successors, |_| C::default(), success, visitor)
+}
+
+/// Lowest cost path guided by a `heuristic` that must never overestimate the remaining cost,
+/// for example the manhattan distance to the goal on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_with(start, successors, heuristic, success, &mut ())
+}
+
+pub fn astar_with<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+    visitor: &mut impl Visitor<N, C>,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored::new(start, C::default());
+    let mut todo = MinHeap::new();
+    todo.push(heuristic(&explored.nodes[0]), (C::default(), 0));
+    visitor.discover(&explored.nodes[0], C::default());
+
+    while let Some((_, (cost, index))) = todo.pop() {
+        // Skip stale entries left behind when a cheaper route was found
+        if cost > explored.costs[index] {
+            continue;
+        }
+        visitor.expand(&explored.nodes[index], cost);
+
+        if success(&explored.nodes[index]) {
+            return Some((explored.path(index), cost));
+        }
+
+        for (next, weight) in successors(&explored.nodes[index]) {
+            let next_cost = cost + weight;
+            let next = match explored.indices.get(&next) {
+                Some(&next) if next_cost >= explored.costs[next] => continue,
+                Some(&next) => {
+                    explored.costs[next] = next_cost;
+                    explored.parents[next] = index;
+                    next
+                }
+                None => explored.add(next, next_cost, index),
+            };
+
+            visitor.discover(&explored.nodes[next], next_cost);
+            todo.push(next_cost + heuristic(&explored.nodes[next]), (next_cost, next));
+        }
+    }
+
+    None
+}
+
+/// Every lowest cost path, like [`astar`] but keeping all ties. Weights must be positive,
+/// otherwise a zero cost cycle would make the number of paths infinite.
+pub fn astar_all<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(ShortestPaths<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_all_with(start, successors, heuristic, success, &mut ())
+}
+
+pub fn astar_all_with<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+    visitor: &mut impl Visitor<N, C>,
+) -> Option<(ShortestPaths<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored::new(start, C::default());
+    let mut parents = vec![Vec::new()];
+    let mut ends = Vec::new();
+    let mut best = None;
+
+    let mut todo = MinHeap::new();
+    todo.push(heuristic(&explored.nodes[0]), (C::default(), 0));
+    visitor.discover(&explored.nodes[0], C::default());
+
+    while let Some((estimate, (cost, index))) = todo.pop() {
+        // Everything left costs more than the goals already found
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if cost > explored.costs[index] {
+            continue;
+        }
+        visitor.expand(&explored.nodes[index], cost);
+
+        if success(&explored.nodes[index]) {
+            best = Some(cost);
+            ends.push(index);
+            continue;
+        }
+
+        for (next, weight) in successors(&explored.nodes[index]) {
+            let next_cost = cost + weight;
+            let next = match explored.indices.get(&next) {
+                Some(&next) if next_cost > explored.costs[next] => continue,
+                Some(&next) if next_cost == explored.costs[next] => {
+                    if !parents[next].contains(&index) {
+                        parents[next].push(index);
+                    }
+                    continue;
+                }
+                Some(&next) => {
+                    explored.costs[next] = next_cost;
+                    parents[next] = vec![index];
+                    next
+                }
+                None => {
+                    parents.push(vec![index]);
+                    explored.add(next, next_cost, index)
+                }
+            };
+
+            visitor.discover(&explored.nodes[next], next_cost);
+            todo.push(next_cost + heuristic(&explored.nodes[next]), (next_cost, next));
+        }
+    }
+
+    best.map(|cost| (ShortestPaths { nodes: explored.nodes, parents, ends }, cost))
+}
+
+/// Number of distinct paths from `start` to a goal, where each path ends at the first goal it
+/// reaches. The graph must not have cycles, otherwise there would be infinitely many paths.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    if success(&start) {
+        return 1;
+    }
+
+    // Paths from each node whose successors have all been counted
+    let mut counts = FastMap::new();
+    // Nodes being counted, with the successors still to visit and the paths found so far
+    let mut stack = vec![(start, None, 0)];
+
+    loop {
+        let (node, todo, total) = stack.last_mut().unwrap();
+        let todo = todo.get_or_insert_with(|| successors(node).into_iter().collect::<Vec<_>>());
+
+        match todo.pop() {
+            Some(next) => {
+                if let Some(&count) = counts.get(&next) {
+                    *total += count;
+                } else if success(&next) {
+                    *total += 1;
+                } else {
+                    stack.push((next, None, 0));
+                }
+            }
+            None => {
+                let (node, _, total) = stack.pop().unwrap();
+                match stack.last_mut() {
+                    Some((_, _, parent)) => *parent += total,
+                    None => return total,
+                }
+                counts.insert(node, total);
+            }
+        }
+    }
+}
+
+/// Every lowest cost path found by [`astar_all`], stored as the graph of parents leading back
+/// from each goal to the start.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<N> {
+    nodes: Vec<N>,
+    parents: Vec<Vec<usize>>,
+    ends: Vec<usize>,
+}
+
+impl<N: Clone> ShortestPaths<N> {
+    /// Each node on at least one of the paths, once.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        let mut seen = BitSet::new(self.nodes.len());
+        let mut todo = self.ends.clone();
+
+        while let Some(index) = todo.pop() {
+            if seen.insert(index) {
+                todo.extend(&self.parents[index]);
+            }
+        }
+
+        seen.iter().map(|index| &self.nodes[index]).collect::<Vec<_>>().into_iter()
+    }
+
+    /// Every path from the start to a goal. There can be exponentially many of them, so prefer
+    /// [`nodes`](ShortestPaths::nodes) when only the set of nodes matters.
+    pub fn paths(&self) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        let mut stack = Vec::new();
+
+        for &end in &self.ends {
+            self.walk(end, &mut stack, &mut paths);
+        }
+
+        paths
+    }
+
+    fn walk(&self, index: usize, stack: &mut Vec<usize>, paths: &mut Vec<Vec<N>>) {
+        stack.push(index);
+
+        if self.parents[index].is_empty() {
+            paths.push(stack.iter().rev().map(|&i| self.nodes[i].clone()).collect());
+        } else {
+            for &parent in &self.parents[index] {
+                self.walk(parent, stack, paths);
+            }
+        }
+
+        stack.pop();
+    }
+}
+
+impl<N: Clone> IntoIterator for ShortestPaths<N> {
+    type Item = Vec<N>;
+    type IntoIter = std::vec::IntoIter<Vec<N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.paths().into_iter()
+    }
+}
+
+/// Nodes seen so far, each stored once and referred to by its index.
+struct Explored<N, C> {
+    nodes: Vec<N>,
+    indices: FastMap<N, usize>,
+    costs: Vec<C>,
+    parents: Vec<usize>,
+}
+
+impl<N: Clone + Eq + Hash, C> Explored<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        let indices = FastMap::build([(start.clone(), 0)]);
+        Explored { nodes: vec![start], indices, costs: vec![cost], parents: vec![NONE] }
+    }
+
+    fn add(&mut self, node: N, cost: C, parent: usize) -> usize {
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.costs.push(cost);
+        self.parents.push(parent);
+        index
+    }
+
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while self.parents[index] != NONE {
+            index = self.parents[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::*;
+    use crate::util::point::*;
+
+    const MAZE: &str = "\
+S...#
+.##.#
+.#...
+...#E";
+
+    fn neighbours(grid: &Grid<u8>, point: Point) -> Vec<Point> {
+        grid.orthogonal_neighbours(point).filter(|&(_, &b)| b != b'#').map(|(p, _)| p).collect()
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        discovered: Vec<Point>,
+        expanded: Vec<(Point, usize)>,
+    }
+
+    impl Visitor<Point, usize> for Recorder {
+        fn discover(&mut self, node: &Point, _cost: usize) {
+            self.discovered.push(*node);
+        }
+
+        fn expand(&mut self, node: &Point, cost: usize) {
+            self.expanded.push((*node, cost));
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse(MAZE);
+        let end = grid.find(b'E').unwrap();
+        let mut recorder = Recorder::default();
+
+        let path = bfs_with(ORIGIN, |&p| neighbours(&grid, p), |&p| p == end, &mut recorder);
+        assert_eq!(path.unwrap().len(), 8);
+        assert_eq!(recorder.expanded.first(), Some(&(ORIGIN, 0)));
+        assert_eq!(recorder.expanded.last(), Some(&(end, 7)));
+        assert!(recorder.discovered.len() >= recorder.expanded.len());
+
+        assert_eq!(bfs(ORIGIN, |&p| neighbours(&grid, p), |&p| p == Point::new(4, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Stepping down into the maze costs more than stepping across
+        let grid = Grid::parse(MAZE);
+        let end = grid.find(b'E').unwrap();
+        let successors = |&p: &Point| {
+            neighbours(&grid, p).into_iter().map(move |n| (n, if n.y > p.y { 3 } else { 1 }))
+        };
+
+        let (path, cost) = dijkstra(ORIGIN, successors, |&p| p == end).unwrap();
+        assert_eq!(cost, 13);
+        assert_eq!(path[..4], [ORIGIN, Point::new(1, 0), Point::new(2, 0), Point::new(3, 0)]);
+
+        let heuristic = |p: &Point| p.manhattan(end) as usize;
+        let mut recorder = Recorder::default();
+        let (path, cost) =
+            astar_with(ORIGIN, successors, heuristic, |&p| p == end, &mut recorder).unwrap();
+        assert_eq!(cost, 13);
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(recorder.expanded.last(), Some(&(end, 13)));
+    }
+
+    #[test]
+    fn test_astar_all() {
+        let grid = Grid::parse("S..\n...\n..E");
+        let end = grid.find(b'E').unwrap();
+        let successors = |&p: &Point| neighbours(&grid, p).into_iter().map(|n| (n, 1));
+        let heuristic = |p: &Point| p.manhattan(end);
+
+        let (paths, cost) = astar_all(ORIGIN, successors, heuristic, |&p| p == end).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(paths.nodes().count(), 9);
+
+        let mut all = paths.paths();
+        all.sort();
+        assert_eq!(all.len(), 6);
+        assert!(all.iter().all(|path| path.len() == 5 && path[0] == ORIGIN && path[4] == end));
+        all.dedup();
+        assert_eq!(all.len(), 6);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Moving only right or down across a 3x3 grid
+        let grid = Grid::parse("S..\n...\n..E");
+        let end = grid.find(b'E').unwrap();
+        let successors =
+            |&p: &Point| [p + RIGHT, p + DOWN].into_iter().filter(|&n| grid.contains(n));
+
+        assert_eq!(count_paths(ORIGIN, successors, |&p| p == end), 6);
+        assert_eq!(count_paths(ORIGIN, successors, |&p| p.y == 1), 3);
+        assert_eq!(count_paths(end, successors, |&p| p == end), 1);
+        assert_eq!(count_paths(end, successors, |&p| p == ORIGIN), 0);
+    }
+}
@@ -0,0 +1,228 @@
+//! Unbounded 2 dimensional grid backed by a [`FastMap`], for puzzles where the area of interest
+//! is not known up front or is mostly empty. This module is designed to work with [`Point`].
+//!
+//! Every point not explicitly set holds a default value, so the grid behaves as if it were
+//! infinite. The traits [`Index`] and [`IndexMut`] are implemented for [`Point`] in the same way
+//! as [`Grid`], with writes to a new point first filling it with the default:
+//!
+//! ```
+//!   # use aoc::util::sparse::SparseGrid;
+//!   # use aoc::util::point::*;
+//!
+//!   let mut grid = SparseGrid::new(0);
+//!   grid[Point::new(-5, 3)] += 2;
+//!
+//!   assert_eq!(grid[Point::new(-5, 3)], 2);
+//!   assert_eq!(grid[Point::new(1000, 1000)], 0);
+//!   assert_eq!(grid.len(), 1);
+//! ```
+//!
+//! The bounding box of every point that has been set is tracked, so that [`to_grid`] can convert
+//! to a dense [`Grid`], for example to render with [`to_grid_string`].
+//!
+//! [`Point`]: crate::util::point
+//! [`Grid`]: crate::util::grid
+//! [`to_grid`]: SparseGrid::to_grid
+//! [`to_grid_string`]: Grid::to_grid_string
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::point::*;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+    cells: FastMap<Point, T>,
+    default: T,
+    /// Inclusive top left and bottom right corners, or `None` until a point is set.
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid { cells: FastMap::new(), default, bounds: None }
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    #[inline]
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Resets a point back to the default. The bounding box does not shrink.
+    #[inline]
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Number of points that have been set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Inclusive top left and bottom right corners of every point that has been set.
+    #[inline]
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Points that have been set along with their values, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    #[inline]
+    fn extend_bounds(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copies the bounding box into a dense grid with unset points holding the default. The top
+    /// left corner of the bounding box becomes the origin of the new grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some((min, max)) = self.bounds else {
+            return Grid { width: 0, height: 0, bytes: Vec::new() };
+        };
+        let width = max.x - min.x + 1;
+        let height = max.y - min.y + 1;
+        let mut bytes = Vec::with_capacity((width * height) as usize);
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                bytes.push(self[Point::new(x, y)].clone());
+            }
+        }
+
+        Grid { width, height, bytes }
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Stores every cell of `grid` that differs from `default`.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut sparse = SparseGrid::new(default);
+        for point in grid.points() {
+            if grid[point] != sparse.default {
+                sparse.insert(point, grid[point].clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T: Default> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut sparse = SparseGrid::new(T::default());
+        sparse.extend(iter);
+        sparse
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        self.cells.get(&index).unwrap_or(&self.default)
+    }
+}
+
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        self.extend_bounds(index);
+        self.cells.entry(index).or_insert_with(|| self.default.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_for_unset_points() {
+        let mut grid = SparseGrid::new(b'.');
+        grid.insert(Point::new(2, -3), b'#');
+
+        assert_eq!(grid[Point::new(2, -3)], b'#');
+        assert_eq!(grid[Point::new(-100, 100)], b'.');
+        assert_eq!(grid.get(Point::new(-100, 100)), None);
+        assert!(!grid.contains(Point::new(-100, 100)));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(grid.bounds(), None);
+
+        grid[Point::new(3, -1)] += 1;
+        grid[Point::new(-2, 4)] += 1;
+        grid[Point::new(0, 0)] += 1;
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(3, 4))));
+
+        // Removing a point leaves the bounding box alone
+        assert_eq!(grid.remove(Point::new(3, -1)), Some(1));
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(3, 4))));
+        assert_eq!(grid[Point::new(3, -1)], 0);
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid: SparseGrid<u8> =
+            [(Point::new(-1, -1), b'#'), (Point::new(1, 0), b'#')].into_iter().collect();
+        let mut grid = SparseGrid { default: b'.', ..grid };
+        grid[Point::new(0, 0)] = b'@';
+
+        assert_eq!(grid.to_grid().to_grid_string(), "#..\n.@#");
+        assert_eq!(SparseGrid::new(b'.').to_grid().to_grid_string(), "");
+    }
+
+    #[test]
+    fn test_from_grid_round_trip() {
+        let dense = Grid::parse("..#\n#..\n...");
+        let sparse = SparseGrid::from_grid(&dense, b'.');
+
+        assert_eq!(sparse.len(), 2);
+        let mut points: Vec<_> = sparse.points().collect();
+        points.sort();
+        assert_eq!(points, vec![Point::new(2, 0), Point::new(0, 1)]);
+
+        // Only the bounding box of set points survives the round trip, dropping the empty last row
+        assert_eq!(sparse.to_grid().to_grid_string(), "..#\n#..");
+    }
+}
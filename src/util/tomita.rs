@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use petgraph::graphmap::{GraphMap,NodeTrait};
 use petgraph::Undirected;
 use petgraph::EdgeType;
+use std::marker::PhantomData;
+use crate::util::bitset::*;
 
 // https://github.com/horasal/clique-rust/blob/master/src/tomita.rs
 
@@ -12,22 +14,33 @@ use petgraph::EdgeType;
 /// connected is a symmetrical bolean matrix, N the number of nodes in the graph,
 /// values of the diagonal should be true.
 pub struct Tomita<N: NodeTrait, E, Ty = Undirected> {
-    graph: GraphMap<N, E, Ty>,
-    max_cliques: Vec<HashSet<N>>
+    /// Nodes in the order of the bits in each set.
+    nodes: Vec<N>,
+    /// Neighbours of each node, as bits numbered like `nodes`.
+    neighbours: Vec<BitSet>,
+    max_cliques: Vec<HashSet<N>>,
+    phantom: PhantomData<(E, Ty)>,
 }
 
 impl<N: NodeTrait, E, Ty: EdgeType> Tomita<N, E, Ty> {
     pub fn new(graphmap: GraphMap<N, E, Ty>) -> Tomita<N, E, Ty> {
+        let nodes: Vec<N> = graphmap.nodes().collect();
+        let neighbours = adjacency(&nodes, |node| graphmap.neighbors(node));
+
         Tomita {
-            graph: graphmap,
-            max_cliques: Vec::new()
+            nodes,
+            neighbours,
+            max_cliques: Vec::new(),
+            phantom: PhantomData,
         }
     }
 
     pub fn compute(&mut self) {
-        let p = self.graph.nodes().collect::<HashSet<N>>();
-        let r = HashSet::new();
-        let x = HashSet::new();
+        let n = self.nodes.len();
+        let mut p = BitSet::new(n);
+        p.extend(0..n);
+        let r = BitSet::new(n);
+        let x = BitSet::new(n);
         self.tomita(p, r, x);
     }
 
@@ -35,42 +48,44 @@ impl<N: NodeTrait, E, Ty: EdgeType> Tomita<N, E, Ty> {
         &self.max_cliques
     }
 
-    fn pivot(&mut self, p: HashSet<N>) -> N {
-        let pvec : Vec<N> = p.clone().into_iter().collect::<Vec<_>>();
-        return pvec[pvec.clone().iter().
-            map(| v | self.graph.neighbors(v.clone()).collect::<HashSet<N>>().
-                intersection(&p).cloned().collect::<Vec<_>>().len()).
-                collect::<Vec<_>>().iter().enumerate().
-                map(|(x, y)| (y, x)).max().unwrap().1 as usize]
+    /// The node in `p` with the most neighbours also in `p`.
+    fn pivot_index(&self, p: &BitSet) -> usize {
+        p.iter()
+            .max_by_key(|&v| {
+                let mut common = p.clone();
+                common.intersect_with(&self.neighbours[v]);
+                common.len()
+            })
+            .unwrap()
     }
 
-    fn tomita(&mut self, p: HashSet<N>, r: HashSet<N>, x: HashSet<N>) {
-        let mut p_fp = p.clone();
-        let mut x_fp = x.clone();
-
+    fn tomita(&mut self, p: BitSet, r: BitSet, x: BitSet) {
         if p.is_empty() {
             if x.is_empty() {
-                self.max_cliques.push(r.clone());
+                self.max_cliques.push(r.iter().map(|v| self.nodes[v]).collect());
             }
             return;
         }
 
-        let u = self.pivot(p);
-        let u_neighbours = self.graph.neighbors(u.clone()).collect::<HashSet<N>>();
-        let real_p = p_fp.difference(&u_neighbours).cloned().collect::<HashSet<N>>();
+        let u = self.pivot_index(&p);
+        let mut real_p = p.clone();
+        real_p.difference_with(&self.neighbours[u]);
 
-        for v in real_p.iter() {
-            let v_neighbours = self.graph.neighbors(v.clone()).collect::<HashSet<N>>();
+        let mut p_fp = p;
+        let mut x_fp = x;
 
-            let p_intersect_v_neighbors = p_fp.intersection(&v_neighbours).cloned().collect();
+        for v in real_p.iter() {
+            let mut p_intersect_v_neighbors = p_fp.clone();
+            p_intersect_v_neighbors.intersect_with(&self.neighbours[v]);
             let mut r_union_v = r.clone();
-            r_union_v.insert(v.clone());
-            let x_intersect_v_neighbors = x_fp.intersection(&v_neighbours).cloned().collect();
+            r_union_v.insert(v);
+            let mut x_intersect_v_neighbors = x_fp.clone();
+            x_intersect_v_neighbors.intersect_with(&self.neighbours[v]);
 
             self.tomita(p_intersect_v_neighbors, r_union_v, x_intersect_v_neighbors);
 
             p_fp.remove(v);
-            x_fp.insert(*v);
+            x_fp.insert(v);
         }
 
     }
@@ -168,8 +183,9 @@ mod tests {
         let mut tomita = Tomita::new(graph);
         
         // Node 'b' should be selected as pivot as it has the most connections (4)
-        let p: HashSet<_> = ["a", "b", "c", "d", "e"].iter().copied().collect();
-        assert_eq!(tomita.pivot(p), "b");
+        let mut p = BitSet::new(5);
+        p.extend(0..5);
+        assert_eq!(tomita.nodes[tomita.pivot_index(&p)], "b");
         
         // Verify we find the correct maximal clique
         tomita.compute();
@@ -0,0 +1,305 @@
+use std::time::{Duration, Instant};
+
+use color_eyre::{eyre::Context, Result};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    layout::{self, Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    DefaultTerminal, Frame,
+};
+
+use crate::aoc2024::day06::{is_loop, walk_grid};
+use crate::util::direction::Direction;
+use crate::util::grid::Grid;
+use crate::util::hash::*;
+use crate::util::point::*;
+
+pub struct App {
+    grid: Grid<u8>,
+    /// Every (position, heading) the guard occupies on the unobstructed patrol, in order
+    route: Vec<(Point, Direction)>,
+    /// Candidate obstruction points, ordered by when the guard first reaches them
+    candidates: Vec<Point>,
+    /// Whether placing an obstruction at the matching candidate traps the guard in a loop
+    loops: Vec<bool>,
+    /// Current frame, covering the route first and then each candidate
+    current_index: usize,
+    /// Whether we are auto-playing
+    playing: bool,
+    /// Delay between frames when playing (ms)
+    frame_delay_ms: u64,
+    /// Viewport top-left in grid coordinates
+    viewport_x: i32,
+    viewport_y: i32,
+}
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn viz(grid: Grid<u8>) -> Result<()> {
+    // setup
+    color_eyre::install()?; // augment errors / panics with easy to read messages
+    let mut terminal = ratatui::init();
+    terminal.clear().context("Failed to clear terminal")?;
+
+    let mut app = App::new(grid);
+    let app_result = app.run(terminal).context("Failed to run application");
+
+    ratatui::restore();
+    app_result
+}
+
+impl App {
+    pub fn new(grid: Grid<u8>) -> Self {
+        let start = grid.find(b'^').unwrap();
+        let route = walk_route(&grid);
+        let candidates = ordered_candidates(&grid, &route, start);
+
+        // Same check as part 2, but run sequentially so each result can be stepped through.
+        let loops = candidates.iter().map(|&p| is_loop(&grid, start, p)).collect();
+
+        Self {
+            grid,
+            route,
+            candidates,
+            loops,
+            current_index: 0,
+            playing: false,
+            frame_delay_ms: 100,
+            viewport_x: 0,
+            viewport_y: 0,
+        }
+    }
+
+    fn frame_count(&self) -> usize {
+        self.route.len() + self.candidates.len()
+    }
+
+    fn in_patrol_phase(&self) -> bool {
+        self.current_index < self.route.len()
+    }
+
+    pub fn run(&mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        // Initial draw, paused
+        terminal
+            .draw(|frame| self.draw(frame))
+            .context("Failed to draw frame")?;
+
+        let mut last_advance = Instant::now();
+
+        loop {
+            if self.playing {
+                // When playing, poll quickly for key events so controls stay responsive
+                if event::poll(Duration::from_millis(10)).context("Failed to poll events")? {
+                    if let Event::Key(key) = event::read().context("Failed to read event")? {
+                        if self.handle_key(key.code) {
+                            break;
+                        }
+                        last_advance = Instant::now();
+                    }
+                }
+
+                // Advance automatically according to frame_delay_ms
+                if last_advance.elapsed() >= Duration::from_millis(self.frame_delay_ms) {
+                    if self.current_index + 1 < self.frame_count() {
+                        self.current_index += 1;
+                        last_advance = Instant::now();
+                    } else {
+                        // Reached the last candidate: stop playing
+                        self.playing = false;
+                    }
+                }
+            } else {
+                // Paused: block until a key event
+                if let Event::Key(key) = event::read().context("Failed to read event")? {
+                    if self.handle_key(key.code) {
+                        break;
+                    }
+                    last_advance = Instant::now();
+                }
+            }
+
+            terminal
+                .draw(|frame| self.draw(frame))
+                .context("Failed to draw frame")?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return true; // quit
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.current_index = 0;
+                self.playing = false;
+            }
+            // Time navigation
+            KeyCode::Char('.') | KeyCode::Char(' ') => {
+                if self.current_index + 1 < self.frame_count() {
+                    self.current_index += 1;
+                }
+            }
+            KeyCode::Char(',') => {
+                self.current_index = self.current_index.saturating_sub(1);
+            }
+            // Jump between the patrol and the obstruction search
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.current_index = self.route.len().min(self.frame_count() - 1);
+            }
+            // Play/pause
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.playing = !self.playing;
+            }
+            // Speed control
+            KeyCode::Char('+') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 4 / 5).max(10);
+            }
+            KeyCode::Char('-') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 5 / 4).min(2000);
+            }
+            // Viewport panning, clamped when drawing
+            KeyCode::Up => self.viewport_y -= 1,
+            KeyCode::Down => self.viewport_y += 1,
+            KeyCode::Left => self.viewport_x -= 1,
+            KeyCode::Right => self.viewport_x += 1,
+            _ => {}
+        }
+
+        false
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(layout::Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(3), Constraint::Length(2)])
+            .split(frame.area());
+
+        let grid_area = chunks[0];
+        let content_w = grid_area.width.saturating_sub(2) as i32;
+        let content_h = grid_area.height.saturating_sub(2) as i32;
+
+        if content_w <= 0 || content_h <= 0 {
+            return;
+        }
+
+        self.viewport_x = self.viewport_x.clamp(0, (self.grid.width - content_w).max(0));
+        self.viewport_y = self.viewport_y.clamp(0, (self.grid.height - content_h).max(0));
+
+        // Work out what has happened up to the current frame.
+        let route_end = (self.current_index + 1).min(self.route.len());
+        let trail: FastSet<_> = self.route[..route_end].iter().map(|&(p, _)| p).collect();
+        let guard = self.in_patrol_phase().then(|| self.route[self.current_index]);
+
+        let tested = self.current_index + 1 - route_end;
+        let mut results = FastMap::with_capacity(tested);
+        for (&p, &is_loop) in self.candidates[..tested].iter().zip(&self.loops) {
+            results.insert(p, is_loop);
+        }
+        let current_candidate = tested.checked_sub(1).map(|i| self.candidates[i]);
+        let loops_found = self.loops[..tested].iter().filter(|&&l| l).count();
+
+        let visible_cols = content_w.min(self.grid.width);
+        let visible_rows = content_h.min(self.grid.height);
+        let mut lines = Vec::with_capacity(visible_rows as usize);
+
+        for row in 0..visible_rows {
+            let mut spans = Vec::with_capacity(visible_cols as usize);
+
+            for col in 0..visible_cols {
+                let p = Point::new(self.viewport_x + col, self.viewport_y + row);
+
+                let span = if let Some((_, heading)) = guard.filter(|&(g, _)| g == p) {
+                    Span::styled((heading.to_arrow().unwrap() as char).to_string(), Style::default().fg(Color::Yellow))
+                } else if self.grid[p] == b'#' {
+                    Span::styled("█", Style::default().fg(Color::White))
+                } else if current_candidate == Some(p) {
+                    Span::styled("O", Style::default().fg(Color::Black).bg(Color::Magenta))
+                } else if let Some(&is_loop) = results.get(&p) {
+                    if is_loop {
+                        Span::styled("O", Style::default().fg(Color::Red))
+                    } else {
+                        Span::styled("·", Style::default().fg(Color::DarkGray))
+                    }
+                } else if self.grid[p] == b'^' {
+                    Span::styled("^", Style::default().fg(Color::Yellow))
+                } else if trail.contains(&p) {
+                    Span::styled(" ", Style::default().bg(Color::Blue))
+                } else {
+                    Span::raw(" ")
+                };
+
+                spans.push(span);
+            }
+
+            lines.push(Line::from(spans));
+        }
+
+        let title = if self.in_patrol_phase() {
+            format!(
+                "Guard patrol – step {} / {}  visited={}",
+                self.current_index,
+                self.route.len() - 1,
+                trail.len(),
+            )
+        } else {
+            format!(
+                "Obstructions – tested {} / {}  loops={}  last={}",
+                tested,
+                self.candidates.len(),
+                loops_found,
+                match current_candidate {
+                    Some(p) if self.loops[tested - 1] => format!("({}, {}) LOOP", p.x, p.y),
+                    Some(p) => format!("({}, {}) exits", p.x, p.y),
+                    None => "-".to_string(),
+                },
+            )
+        };
+
+        let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(paragraph, grid_area);
+
+        let mode_label = if self.playing { "PLAY" } else { "PAUSE" };
+        let status_text = format!(
+            "[{}]  |  [,] prev  [.] next  [N] skip to obstructions  [Arrows] pan  [P] play/pause  [+/-] speed={}ms  [R] reset  [Q] quit",
+            mode_label, self.frame_delay_ms
+        );
+        frame.render_widget(Paragraph::new(status_text), chunks[1]);
+    }
+}
+
+/// Step-by-step version of [`walk_grid`] that keeps the order of the guard's moves, and the
+/// heading at each one, so the patrol can be replayed.
+fn walk_route(grid: &Grid<u8>) -> Vec<(Point, Direction)> {
+    let mut position = grid.find(b'^').unwrap();
+    let mut heading = Direction::North;
+    let mut route = vec![(position, heading)];
+
+    loop {
+        let next = position + heading;
+        if !grid.contains(next) {
+            return route;
+        }
+        if grid[next] == b'#' {
+            heading = heading.turn_right();
+        } else {
+            position = next;
+        }
+        route.push((position, heading));
+    }
+}
+
+/// Every location on the patrol except the start is a candidate for an obstruction. They are
+/// tested in the order the guard first reaches them, so the search follows the route.
+fn ordered_candidates(grid: &Grid<u8>, route: &[(Point, Direction)], start: Point) -> Vec<Point> {
+    let mut remaining = walk_grid(grid);
+    remaining.remove(&start);
+
+    route.iter().filter_map(|&(p, _)| remaining.remove(&p).then_some(p)).collect()
+}
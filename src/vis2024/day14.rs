@@ -0,0 +1,344 @@
+use std::time::{Duration, Instant};
+
+use color_eyre::{eyre::Context, Result};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    DefaultTerminal, Frame,
+};
+
+use crate::aoc2024::day14::{move_robots, quadrant_counts, score_quadrant_counts, Robot};
+use crate::util::grid::Grid;
+use crate::util::point::{Point, ORTHOGONAL};
+
+/// Side length of the square bins used when measuring how spread out the robots are.
+const BIN_SIZE: u32 = 5;
+
+pub struct App {
+    robots: Vec<Robot>,
+    width: u32,
+    height: u32,
+    /// Positions repeat after `width * height` seconds, so this is every distinct frame
+    period: u32,
+    /// Entropy of the robot positions for every second in the period
+    entropies: Vec<f64>,
+    /// The second with the lowest entropy, which is where the tree appears
+    tree_second: u32,
+    /// Current second shown. Positions are computed directly from this, never by replaying.
+    current_second: u32,
+    /// Whether we are auto-playing
+    playing: bool,
+    /// Delay between frames when playing (ms)
+    frame_delay_ms: u64,
+    /// Viewport top-left in grid coordinates
+    viewport_x: i32,
+    viewport_y: i32,
+}
+
+pub fn parse(input: &str) -> Vec<Robot> {
+    crate::aoc2024::day14::parse(input)
+}
+
+pub fn viz(robots: Vec<Robot>) -> Result<()> {
+    // setup
+    color_eyre::install()?; // augment errors / panics with easy to read messages
+    let mut terminal = ratatui::init();
+    terminal.clear().context("Failed to clear terminal")?;
+
+    let mut app = App::new(robots);
+    let app_result = app.run(terminal).context("Failed to run application");
+
+    ratatui::restore();
+    app_result
+}
+
+impl App {
+    pub fn new(robots: Vec<Robot>) -> Self {
+        // The example in the puzzle text uses a smaller room than the real input.
+        let is_example = robots.iter().all(|r| r.position.x < 11 && r.position.y < 7);
+        let (width, height) = if is_example { (11, 7) } else { (101, 103) };
+        let period = width * height;
+
+        let entropies: Vec<_> = (0..period)
+            .map(|second| {
+                let positions: Vec<_> = move_robots(&robots, width, height, second).collect();
+                entropy(&positions, width, height)
+            })
+            .collect();
+
+        let tree_second = (0..period)
+            .min_by(|&a, &b| entropies[a as usize].total_cmp(&entropies[b as usize]))
+            .unwrap();
+
+        Self {
+            robots,
+            width,
+            height,
+            period,
+            entropies,
+            tree_second,
+            current_second: 0,
+            playing: false,
+            frame_delay_ms: 100,
+            viewport_x: 0,
+            viewport_y: 0,
+        }
+    }
+
+    pub fn run(&mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        // Initial draw, paused
+        terminal
+            .draw(|frame| self.draw(frame))
+            .context("Failed to draw frame")?;
+
+        let mut last_advance = Instant::now();
+
+        loop {
+            if self.playing {
+                // When playing, poll quickly for key events so scrubbing stays responsive
+                if event::poll(Duration::from_millis(10)).context("Failed to poll events")? {
+                    if let Event::Key(key) = event::read().context("Failed to read event")? {
+                        if self.handle_key(key.code) {
+                            break;
+                        }
+                        last_advance = Instant::now();
+                    }
+                }
+
+                // Advance automatically according to frame_delay_ms
+                if last_advance.elapsed() >= Duration::from_millis(self.frame_delay_ms) {
+                    self.seek(1);
+                    last_advance = Instant::now();
+                }
+            } else {
+                // Paused: block until a key event
+                if let Event::Key(key) = event::read().context("Failed to read event")? {
+                    if self.handle_key(key.code) {
+                        break;
+                    }
+                    last_advance = Instant::now();
+                }
+            }
+
+            terminal
+                .draw(|frame| self.draw(frame))
+                .context("Failed to draw frame")?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return true; // quit
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.current_second = 0;
+                self.playing = false;
+            }
+            // Time navigation, wrapping around the period
+            KeyCode::Char('.') | KeyCode::Char(' ') => self.seek(1),
+            KeyCode::Char(',') => self.seek(-1),
+            KeyCode::Char(']') => self.seek(self.width as i64),
+            KeyCode::Char('[') => self.seek(-(self.width as i64)),
+            KeyCode::Char('>') => self.seek(1000),
+            KeyCode::Char('<') => self.seek(-1000),
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                self.current_second = self.tree_second;
+                self.playing = false;
+            }
+            // Play/pause
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.playing = !self.playing;
+            }
+            // Speed control
+            KeyCode::Char('+') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 4 / 5).max(10);
+            }
+            KeyCode::Char('-') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 5 / 4).min(2000);
+            }
+            // Viewport panning, clamped when drawing
+            KeyCode::Up => self.viewport_y -= 1,
+            KeyCode::Down => self.viewport_y += 1,
+            KeyCode::Left => self.viewport_x -= 1,
+            KeyCode::Right => self.viewport_x += 1,
+            _ => {}
+        }
+
+        false
+    }
+
+    fn seek(&mut self, delta: i64) {
+        self.current_second = (self.current_second as i64 + delta).rem_euclid(self.period as i64) as u32;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(3), Constraint::Length(2)])
+            .split(frame.area());
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(3), Constraint::Length(34)])
+            .split(chunks[0]);
+
+        let positions: Vec<_> = move_robots(&self.robots, self.width, self.height, self.current_second).collect();
+        let at_tree = self.current_second == self.tree_second;
+
+        self.draw_room(frame, &positions, at_tree, columns[0]);
+        self.draw_metrics(frame, &positions, at_tree, columns[1]);
+
+        let mode_label = if self.playing { "PLAY" } else { "PAUSE" };
+        let status_text = format!(
+            "[{}]  |  [,/.] ±1s  [[/]] ±{}s  [</>] ±1000s  [T] tree  [Arrows] pan  [P] play/pause  [+/-] speed={}ms  [R] reset  [Q] quit",
+            mode_label, self.width, self.frame_delay_ms
+        );
+        frame.render_widget(Paragraph::new(status_text), chunks[1]);
+    }
+
+    /// Draws the room using half blocks, so each character cell covers two rows of robots.
+    fn draw_room(&mut self, frame: &mut Frame, positions: &[(u32, u32)], at_tree: bool, area: Rect) {
+        let content_w = area.width.saturating_sub(2) as i32;
+        let content_h = area.height.saturating_sub(2) as i32;
+
+        if content_w <= 0 || content_h <= 0 {
+            return;
+        }
+
+        let mut occupied = Grid::new(self.width as i32, self.height as i32, false);
+        for &(x, y) in positions {
+            occupied[Point::new(x as i32, y as i32)] = true;
+        }
+
+        let rows = (occupied.height + 1) / 2;
+        self.viewport_x = self.viewport_x.clamp(0, (occupied.width - content_w).max(0));
+        self.viewport_y = self.viewport_y.clamp(0, (rows - content_h).max(0));
+
+        let colour = if at_tree { Color::LightGreen } else { Color::Green };
+        let is_set = |x: i32, y: i32| {
+            let p = Point::new(x, y);
+            occupied.contains(p) && occupied[p]
+        };
+
+        let mut lines = Vec::with_capacity(content_h.min(rows) as usize);
+
+        for row in 0..content_h.min(rows) {
+            let y = (self.viewport_y + row) * 2;
+            let mut spans = Vec::with_capacity(content_w.min(occupied.width) as usize);
+
+            for col in 0..content_w.min(occupied.width) {
+                let x = self.viewport_x + col;
+                let ch = match (is_set(x, y), is_set(x, y + 1)) {
+                    (true, true) => "█",
+                    (true, false) => "▀",
+                    (false, true) => "▄",
+                    (false, false) => " ",
+                };
+                spans.push(Span::styled(ch, Style::default().fg(colour)));
+            }
+
+            lines.push(Line::from(spans));
+        }
+
+        let title = format!(
+            "Restroom – second {} / {}{}",
+            self.current_second,
+            self.period - 1,
+            if at_tree { "  [TREE]" } else { "" }
+        );
+        let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn draw_metrics(&self, frame: &mut Frame, positions: &[(u32, u32)], at_tree: bool, area: Rect) {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(12), Constraint::Min(3)])
+            .split(area);
+
+        let counts = quadrant_counts(positions.iter().copied(), self.width, self.height);
+        let entropy = self.entropies[self.current_second as usize];
+        let min_entropy = self.entropies[self.tree_second as usize];
+        let highlight = Style::default().fg(if at_tree { Color::LightGreen } else { Color::White });
+
+        let lines = vec![
+            Line::from(format!("Second:     {}", self.current_second)),
+            Line::from(""),
+            Line::from("Quadrants:"),
+            Line::from(format!("  {:>5} {:>5}", counts[0], counts[1])),
+            Line::from(format!("  {:>5} {:>5}", counts[2], counts[3])),
+            Line::from(format!("Safety:     {}", score_quadrant_counts(&counts))),
+            Line::from(""),
+            Line::from(Span::styled(format!("Entropy:    {entropy:.3} bits"), highlight)),
+            Line::from(format!("Clustered:  {:.1}%", 100.0 * clustering(positions, self.width, self.height))),
+            Line::from(format!("Tree at:    {} ({min_entropy:.3})", self.tree_second)),
+        ];
+
+        let metrics = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Metrics"));
+        frame.render_widget(metrics, parts[0]);
+
+        // Plot how ordered the room is for the seconds leading up to the current one, so
+        // the tree shows as a spike against the background noise.
+        let max_entropy = self.entropies.iter().copied().fold(f64::MIN, f64::max);
+        let window = parts[1].width.saturating_sub(2) as u32;
+        let data: Vec<u64> = (0..window)
+            .map(|i| {
+                let second = (self.current_second + self.period + i + 1 - window) % self.period;
+                ((max_entropy - self.entropies[second as usize]) * 100.0) as u64
+            })
+            .collect();
+
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title("Order (1 - entropy)"))
+            .style(Style::default().fg(Color::Yellow))
+            .max((((max_entropy - min_entropy) * 100.0) as u64).max(1))
+            .data(&data);
+        frame.render_widget(sparkline, parts[1]);
+    }
+}
+
+/// Shannon entropy of the robot positions, grouped into square bins. Random scatter spreads
+/// robots evenly over the bins, while a picture concentrates them into a few.
+fn entropy(positions: &[(u32, u32)], width: u32, height: u32) -> f64 {
+    let bins_x = width.div_ceil(BIN_SIZE);
+    let bins_y = height.div_ceil(BIN_SIZE);
+    let mut bins = vec![0u32; (bins_x * bins_y) as usize];
+
+    for &(x, y) in positions {
+        bins[((y / BIN_SIZE) * bins_x + x / BIN_SIZE) as usize] += 1;
+    }
+
+    let total = positions.len() as f64;
+    bins.iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Fraction of robots that have at least one other robot orthogonally adjacent.
+fn clustering(positions: &[(u32, u32)], width: u32, height: u32) -> f64 {
+    let mut occupied = Grid::new(width as i32, height as i32, false);
+    for &(x, y) in positions {
+        occupied[Point::new(x as i32, y as i32)] = true;
+    }
+
+    let clustered = positions
+        .iter()
+        .filter(|&&(x, y)| {
+            let p = Point::new(x as i32, y as i32);
+            ORTHOGONAL.iter().any(|&d| occupied.contains(p + d) && occupied[p + d])
+        })
+        .count();
+
+    clustered as f64 / positions.len().max(1) as f64
+}
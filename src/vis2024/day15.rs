@@ -0,0 +1,367 @@
+use std::time::{Duration, Instant};
+
+use color_eyre::{eyre::Context, Result};
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    layout::{self, Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    DefaultTerminal, Frame,
+};
+
+use crate::aoc2024::day15::{do_moves, do_wide_moves, score_grid, wide_grid};
+use crate::util::direction::Direction;
+use crate::util::grid::Grid;
+use crate::util::hash::*;
+use crate::util::point::*;
+
+/// A snapshot of the warehouse is kept every this many moves, so any frame can be rebuilt by
+/// replaying at most this many moves instead of the whole list.
+const CHECKPOINT_INTERVAL: usize = 100;
+
+/// The warehouse replayed with either the narrow rules of part 1 or the wide rules of part 2.
+struct Replay {
+    wide: bool,
+    checkpoints: Vec<Grid<u8>>,
+}
+
+impl Replay {
+    fn new(start: Grid<u8>, moves: &[Direction], wide: bool) -> Self {
+        let mut checkpoints = Vec::with_capacity(moves.len() / CHECKPOINT_INTERVAL + 1);
+        let mut grid = start;
+
+        for (i, &mv) in moves.iter().enumerate() {
+            if i.is_multiple_of(CHECKPOINT_INTERVAL) {
+                checkpoints.push(grid.clone());
+            }
+            apply_move(&mut grid, mv, wide);
+        }
+        if moves.len().is_multiple_of(CHECKPOINT_INTERVAL) {
+            checkpoints.push(grid);
+        }
+
+        Replay { wide, checkpoints }
+    }
+
+    /// The warehouse after the first `n` moves, along with the boxes pushed by the last of them.
+    fn state(&self, moves: &[Direction], n: usize) -> (Grid<u8>, Vec<Point>) {
+        let from = n / CHECKPOINT_INTERVAL * CHECKPOINT_INTERVAL;
+        let mut grid = self.checkpoints[n / CHECKPOINT_INTERVAL].clone();
+        let mut pushed = Vec::new();
+
+        for &mv in &moves[from..n] {
+            pushed = apply_move(&mut grid, mv, self.wide);
+        }
+
+        (grid, pushed)
+    }
+}
+
+pub struct App {
+    moves: Vec<Direction>,
+    narrow: Replay,
+    wide: Replay,
+    /// Whether we are showing the part 2 warehouse
+    show_wide: bool,
+    /// Number of moves made so far, shared between both warehouses
+    current_index: usize,
+    /// Whether we are auto-playing
+    playing: bool,
+    /// Delay between frames when playing (ms)
+    frame_delay_ms: u64,
+    /// Viewport top-left in grid coordinates
+    viewport_x: i32,
+    viewport_y: i32,
+}
+
+pub fn parse(input: &str) -> (Grid<u8>, Vec<Point>) {
+    crate::aoc2024::day15::parse(input)
+}
+
+pub fn viz(input: (Grid<u8>, Vec<Point>)) -> Result<()> {
+    // setup
+    color_eyre::install()?; // augment errors / panics with easy to read messages
+    let mut terminal = ratatui::init();
+    terminal.clear().context("Failed to clear terminal")?;
+
+    let mut app = App::new(input);
+    let app_result = app.run(terminal).context("Failed to run application");
+
+    ratatui::restore();
+    app_result
+}
+
+impl App {
+    pub fn new((grid, moves): (Grid<u8>, Vec<Point>)) -> Self {
+        let moves: Vec<_> = moves.into_iter().map(|mv| Direction::try_from(mv).unwrap()).collect();
+        let wide = Replay::new(wide_grid(&grid), &moves, true);
+        let narrow = Replay::new(grid, &moves, false);
+
+        Self {
+            moves,
+            narrow,
+            wide,
+            show_wide: false,
+            current_index: 0,
+            playing: false,
+            frame_delay_ms: 200,
+            viewport_x: 0,
+            viewport_y: 0,
+        }
+    }
+
+    pub fn run(&mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        // Initial draw, paused
+        terminal
+            .draw(|frame| self.draw(frame))
+            .context("Failed to draw frame")?;
+
+        let mut last_advance = Instant::now();
+
+        loop {
+            if self.playing {
+                // When playing, poll quickly for key events so controls stay responsive
+                if event::poll(Duration::from_millis(10)).context("Failed to poll events")? {
+                    if let Event::Key(key) = event::read().context("Failed to read event")? {
+                        if self.handle_key(key.code) {
+                            break;
+                        }
+                        last_advance = Instant::now();
+                    }
+                }
+
+                // Advance automatically according to frame_delay_ms
+                if last_advance.elapsed() >= Duration::from_millis(self.frame_delay_ms) {
+                    if self.current_index < self.moves.len() {
+                        self.current_index += 1;
+                        last_advance = Instant::now();
+                    } else {
+                        // All moves made: stop playing
+                        self.playing = false;
+                    }
+                }
+            } else {
+                // Paused: block until a key event
+                if let Event::Key(key) = event::read().context("Failed to read event")? {
+                    if self.handle_key(key.code) {
+                        break;
+                    }
+                    last_advance = Instant::now();
+                }
+            }
+
+            terminal
+                .draw(|frame| self.draw(frame))
+                .context("Failed to draw frame")?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return true; // quit
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.current_index = 0;
+                self.playing = false;
+            }
+            // Time navigation
+            KeyCode::Char('.') | KeyCode::Char(' ') => self.seek(1),
+            KeyCode::Char(',') => self.seek(-1),
+            KeyCode::Char(']') => self.seek(CHECKPOINT_INTERVAL as i64),
+            KeyCode::Char('[') => self.seek(-(CHECKPOINT_INTERVAL as i64)),
+            // Switch between the narrow and wide warehouse
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.show_wide = !self.show_wide;
+            }
+            // Play/pause
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.playing = !self.playing;
+            }
+            // Speed control
+            KeyCode::Char('+') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 4 / 5).max(10);
+            }
+            KeyCode::Char('-') => {
+                self.frame_delay_ms = (self.frame_delay_ms * 5 / 4).min(2000);
+            }
+            // Viewport panning, clamped when drawing
+            KeyCode::Up => self.viewport_y -= 1,
+            KeyCode::Down => self.viewport_y += 1,
+            KeyCode::Left => self.viewport_x -= 1,
+            KeyCode::Right => self.viewport_x += 1,
+            _ => {}
+        }
+
+        false
+    }
+
+    fn seek(&mut self, delta: i64) {
+        self.current_index = (self.current_index as i64 + delta).clamp(0, self.moves.len() as i64) as usize;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(layout::Direction::Vertical)
+            .margin(1)
+            .constraints([Constraint::Min(3), Constraint::Length(2)])
+            .split(frame.area());
+
+        let grid_area = chunks[0];
+        let content_w = grid_area.width.saturating_sub(2) as i32;
+        let content_h = grid_area.height.saturating_sub(2) as i32;
+
+        if content_w <= 0 || content_h <= 0 {
+            return;
+        }
+
+        let replay = if self.show_wide { &self.wide } else { &self.narrow };
+        let (grid, pushed) = replay.state(&self.moves, self.current_index);
+        let pushed_set: FastSet<_> = pushed.iter().copied().collect();
+
+        self.viewport_x = self.viewport_x.clamp(0, (grid.width - content_w).max(0));
+        self.viewport_y = self.viewport_y.clamp(0, (grid.height - content_h).max(0));
+
+        let visible_cols = content_w.min(grid.width);
+        let visible_rows = content_h.min(grid.height);
+        let mut lines = Vec::with_capacity(visible_rows as usize);
+
+        for row in 0..visible_rows {
+            let mut spans = Vec::with_capacity(visible_cols as usize);
+
+            for col in 0..visible_cols {
+                let p = Point::new(self.viewport_x + col, self.viewport_y + row);
+                let span = match grid[p] {
+                    b'#' => Span::styled("█", Style::default().fg(Color::White)),
+                    b'@' => Span::styled("@", Style::default().fg(Color::Red)),
+                    b @ (b'O' | b'[' | b']') => {
+                        let style = if pushed_set.contains(&p) {
+                            Style::default().fg(Color::Black).bg(Color::Magenta)
+                        } else {
+                            Style::default().fg(Color::Yellow)
+                        };
+                        Span::styled((b as char).to_string(), style)
+                    }
+                    _ => Span::raw(" "),
+                };
+                spans.push(span);
+            }
+
+            lines.push(Line::from(spans));
+        }
+
+        let last_move = match self.current_index.checked_sub(1) {
+            None => "start".to_string(),
+            Some(i) => {
+                let mv = self.moves[i].to_arrow().unwrap() as char;
+                let robot = grid.find(b'@').unwrap();
+                let (before, _) = replay.state(&self.moves, i);
+                if before[robot] == b'@' {
+                    format!("{mv} blocked")
+                } else if pushed.is_empty() {
+                    format!("{mv} moved")
+                } else {
+                    // Wide boxes take up two cells each
+                    let boxes = if replay.wide { pushed.len() / 2 } else { pushed.len() };
+                    format!("{mv} pushed {boxes} box{}", if boxes == 1 { "" } else { "es" })
+                }
+            }
+        };
+        let next_move = self.moves.get(self.current_index).map_or('-', |mv| mv.to_arrow().unwrap() as char);
+        let gps = score_grid(&grid, if replay.wide { b'[' } else { b'O' });
+
+        let title = format!(
+            "{} warehouse – move {} / {}  last={}  next={}  gps={}",
+            if replay.wide { "Wide" } else { "Narrow" },
+            self.current_index,
+            self.moves.len(),
+            last_move,
+            next_move,
+            gps,
+        );
+        let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(paragraph, grid_area);
+
+        let mode_label = if self.playing { "PLAY" } else { "PAUSE" };
+        let status_text = format!(
+            "[{}]  |  [,] prev  [.] next  [[/]] ±{}  [W] narrow/wide  [Arrows] pan  [P] play/pause  [+/-] speed={}ms  [R] reset  [Q] quit",
+            mode_label, CHECKPOINT_INTERVAL, self.frame_delay_ms
+        );
+        frame.render_widget(Paragraph::new(status_text), chunks[1]);
+    }
+}
+
+/// Makes a single move using the solver, returning where the boxes it pushed ended up.
+fn apply_move(grid: &mut Grid<u8>, mv: Direction, wide: bool) -> Vec<Point> {
+    let mv = Point::from(mv);
+    let start = grid.find(b'@').unwrap();
+    let chain = pushed_boxes(grid, start, mv);
+
+    if wide {
+        let mut robot = start;
+        do_wide_moves(grid, &mut robot, &[mv]);
+    } else {
+        do_moves(grid, &[mv]);
+    }
+
+    // The robot only leaves its cell when the whole chain was free to move.
+    if grid[start] == b'@' {
+        Vec::new()
+    } else {
+        chain.into_iter().map(|p| p + mv).collect()
+    }
+}
+
+/// Finds every box cell the robot would push by moving in `dir`, following both halves of wide
+/// boxes in the same way as the part 2 solver.
+fn pushed_boxes(grid: &Grid<u8>, robot: Point, dir: Point) -> Vec<Point> {
+    let mut todo = vec![robot];
+    let mut seen = FastSet::new();
+    let mut index = 0;
+
+    while index < todo.len() {
+        let next = todo[index] + dir;
+        index += 1;
+
+        let halves = match grid[next] {
+            b'O' => [Some(next), None],
+            b'[' => [Some(next), Some(next + RIGHT)],
+            b']' => [Some(next), Some(next + LEFT)],
+            _ => continue,
+        };
+
+        for half in halves.into_iter().flatten() {
+            if seen.insert(half) {
+                todo.push(half);
+            }
+        }
+    }
+
+    todo.split_off(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_move() {
+        let mut grid = Grid::parse("#.O@#");
+        assert_eq!(apply_move(&mut grid, Direction::West, false), vec![Point::new(1, 0)]);
+        assert!(apply_move(&mut grid, Direction::West, false).is_empty());
+
+        let mut grid = Grid::parse("##..[][]@.##");
+        assert_eq!(
+            apply_move(&mut grid, Direction::West, true),
+            vec![Point::new(6, 0), Point::new(5, 0), Point::new(4, 0), Point::new(3, 0)]
+        );
+        assert_eq!(grid.bytes, b"##.[][]@..##");
+
+        let mut grid = Grid::parse("########\n##....##\n##.[].##\n##..@.##");
+        assert_eq!(apply_move(&mut grid, Direction::North, true), vec![Point::new(4, 1), Point::new(3, 1)]);
+        assert!(apply_move(&mut grid, Direction::North, true).is_empty());
+    }
+}
@@ -1,8 +1,9 @@
+use crate::util::direction::Direction;
 use crate::util::grid::*;
 use crate::util::hash::*;
 use crate::util::point::*;
 use crate::aoc2024::day16::*;
-use pathfinding::prelude::astar;
+use crate::util::search::astar;
 
 use color_eyre::{eyre::Context, Result};
 use std::time::Duration;
@@ -193,9 +194,9 @@ impl App {
                 
                 // Update best path only when we find a closer point
                 let start = self.grid.find(b'S').unwrap();
-                let reindeer = Reindeer { pos: start, dir: RIGHT };
+                let reindeer = Reindeer { pos: start, dir: Direction::East };
                 if let Some((path, _)) = astar(
-                    &reindeer,
+                    reindeer,
                     |r| get_successors(r, &self.grid),
                     |r| r.pos.manhattan(current) as u32,
                     |r| r.pos == current
@@ -131,9 +131,9 @@ fn test_count_connected_regions() {
 }
 
 #[test]
-fn test_score_borders() {
+fn test_fence_price() {
     let grid = parse(EXAMPLE1);
-    let score = score_borders(&grid);
+    let score = fence_price(&grid, |region| region.perimeter);
     
     // Top edge: A(4) four times
     // Right edge: A(4), D(1), C(3), C(3)
@@ -152,47 +152,20 @@ fn test_score_borders() {
 #[test]
 fn test_count_region_sides() {
     let grid = parse(EXAMPLE1);
-    let regions = create_regions(&grid);
-    
-    let a_region = regions.iter()
-        .find(|r| r.plant_type == b'A')
-        .unwrap();
-    
-    assert_eq!(a_region.points.len(), 4);
-    assert_eq!(a_region.side_count, 4);
-    
-    let b_region = regions.iter()
-        .find(|r| r.plant_type == b'B')
-        .unwrap();
-    
-    assert_eq!(b_region.points.len(), 4);
-    assert_eq!(b_region.side_count, 4);
-    
-    let c_region = regions.iter()
-        .find(|r| r.plant_type == b'C')
-        .unwrap();
-    
+    let regions = grid.regions(|a, b| a == b).stats;
+    let region = |plant: u8| regions.iter().find(|r| grid[r.start] == plant).unwrap();
+
+    assert_eq!((region(b'A').area, region(b'A').sides), (4, 4));
+    assert_eq!((region(b'B').area, region(b'B').sides), (4, 4));
+
     // C region is a snake shape:
     //   C
     //   CC
     //    C
-    assert_eq!(c_region.points.len(), 4);
-    assert_eq!(c_region.side_count, 8); // Has 8 sides due to its shape
-    
-    let d_region = regions.iter()
-        .find(|r| r.plant_type == b'D')
-        .unwrap();
-    
-    assert_eq!(d_region.points.len(), 1);
-    assert_eq!(d_region.side_count, 4);
-    
-    let e_region = regions.iter()
-        .find(|r| r.plant_type == b'E')
-        .unwrap();
-    
-    assert_eq!(e_region.points.len(), 3);
-    assert_eq!(e_region.side_count, 4);
-    
+    assert_eq!((region(b'C').area, region(b'C').sides), (4, 8)); // Has 8 sides due to its shape
+    assert_eq!((region(b'D').area, region(b'D').sides), (1, 4));
+    assert_eq!((region(b'E').area, region(b'E').sides), (3, 4));
+
     assert_eq!(regions.len(), 5); // A, B, C, D, E regions
 }
 
@@ -82,7 +82,7 @@ fn move_test_individual_moves() {
         #...O..#
         #......#
         ########"});
-    do_moves(&mut grid, &vec![LEFT]);
+    do_moves(&mut grid, &[LEFT]);
 
     // first move is blocked by wall
     assert_eq!(grid.to_grid_string(), indoc! {"\
@@ -96,7 +96,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // second move is up into space
-    do_moves(&mut grid, &vec![UP]);
+    do_moves(&mut grid, &[UP]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #.@O.O.#
@@ -108,7 +108,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 3rd move is up - hits wall
-    do_moves(&mut grid, &vec![UP]);
+    do_moves(&mut grid, &[UP]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #.@O.O.#
@@ -120,7 +120,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 4th move is right - pushes 1 box
-    do_moves(&mut grid, &vec![RIGHT]);
+    do_moves(&mut grid, &[RIGHT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #..@OO.#
@@ -132,7 +132,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 5th move is right - pushes 2 boxes
-    do_moves(&mut grid, &vec![RIGHT]);
+    do_moves(&mut grid, &[RIGHT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #...@OO#
@@ -144,7 +144,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 6th move is down - pushes 4 boxes
-    do_moves(&mut grid, &vec![DOWN]);
+    do_moves(&mut grid, &[DOWN]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -156,7 +156,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 7th move is down - pushes nothing
-    do_moves(&mut grid, &vec![DOWN]);
+    do_moves(&mut grid, &[DOWN]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -168,7 +168,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 8th move is left into space
-    do_moves(&mut grid, &vec![LEFT]);
+    do_moves(&mut grid, &[LEFT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -180,7 +180,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 9th move is down into space
-    do_moves(&mut grid, &vec![DOWN]);
+    do_moves(&mut grid, &[DOWN]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -192,7 +192,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 10th move is RIGHT moving 1 box
-    do_moves(&mut grid, &vec![RIGHT]);
+    do_moves(&mut grid, &[RIGHT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -204,7 +204,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 11th move is RIGHT moving 1 box
-    do_moves(&mut grid, &vec![RIGHT]);
+    do_moves(&mut grid, &[RIGHT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -216,7 +216,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 12th move is down into space
-    do_moves(&mut grid, &vec![DOWN]);
+    do_moves(&mut grid, &[DOWN]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -228,7 +228,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 13th move is left moving 1 box
-    do_moves(&mut grid, &vec![LEFT]);
+    do_moves(&mut grid, &[LEFT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -240,7 +240,7 @@ fn move_test_individual_moves() {
         ########"});
 
     // 14th move is left moving nothing
-    do_moves(&mut grid, &vec![LEFT]);
+    do_moves(&mut grid, &[LEFT]);
     assert_eq!(grid.to_grid_string(), indoc! {"\
         ########
         #....OO#
@@ -32,6 +32,19 @@ const INPUT1: &str = "\
 1,6
 2,0";
 
+#[test]
+fn fast_bfs_test_part1() {
+    let mut grid = Grid::new(7, 7, u16::MAX);
+    for (i, [x, y]) in INPUT1.iter_signed().chunk::<2>().enumerate() {
+        grid[Point::new(x, y)] = i as u16;
+    }
+
+    assert_eq!(fast_bfs(&grid, 12), Some(22));
+    // The 21st byte to fall, at 6,1, cuts off the exit
+    assert!(fast_bfs(&grid, 20).is_some());
+    assert_eq!(fast_bfs(&grid, 21), None);
+}
+
 #[test]
 fn do_bfs_test_part1() {
     let mut grid = Grid::new(7, 7, u16::MAX);